@@ -0,0 +1,172 @@
+use rand::RngCore;
+
+use crate::{
+    g1_point::g1_mul, g2_point::pairing_check, BLSError, BLSSignature, G1Point, G2Point,
+    HashToCurve, G2_MINUS_ONE,
+};
+
+/// # Batch Verify
+///
+/// Verifies many independent `(pubkey, message, signature)` triples with a single multi-pairing.
+/// Each triple is weighted by a random 128-bit scalar `r_i`, and we check that:
+///
+/// e(Σ r_i·σ_i, -G2) · Π e(r_i·H(m_i), pk_i) == 1
+///
+/// so a forged signature can only slip through with negligible probability. Use
+/// `batch_verify_find_invalid` to find out which triples failed.
+pub fn batch_verify<H: HashToCurve, S: BLSSignature>(
+    pubkeys: &[G2Point],
+    messages: &[&[u8]],
+    signatures: &[S],
+) -> Result<(), BLSError> {
+    let triples = prepare::<H, S>(pubkeys, messages, signatures)?;
+
+    if triples.iter().any(|t| t.is_none()) {
+        return Err(BLSError::BLSVerificationError);
+    }
+
+    let indices: Vec<usize> = (0..triples.len()).collect();
+    check(&triples, &indices)
+}
+
+/// # Batch Verify Find Invalid
+///
+/// Same as `batch_verify`, but when the batch fails it bisects it to identify the failing
+/// triples. Returns the indices of all invalid triples, which is empty if the batch is valid.
+pub fn batch_verify_find_invalid<H: HashToCurve, S: BLSSignature>(
+    pubkeys: &[G2Point],
+    messages: &[&[u8]],
+    signatures: &[S],
+) -> Result<Vec<usize>, BLSError> {
+    let triples = prepare::<H, S>(pubkeys, messages, signatures)?;
+
+    // Triples we failed to hash or decode are invalid without needing a pairing
+    let mut invalid: Vec<usize> = (0..triples.len()).filter(|&i| triples[i].is_none()).collect();
+    let indices: Vec<usize> = (0..triples.len()).filter(|&i| triples[i].is_some()).collect();
+
+    bisect(&triples, &indices, &mut invalid)?;
+
+    invalid.sort_unstable();
+    Ok(invalid)
+}
+
+/// A prepared triple of hashed message, public key and signature bytes.
+type Triple = ([u8; 64], [u8; 128], [u8; 64]);
+
+fn prepare<H: HashToCurve, S: BLSSignature>(
+    pubkeys: &[G2Point],
+    messages: &[&[u8]],
+    signatures: &[S],
+) -> Result<Vec<Option<Triple>>, BLSError> {
+    if pubkeys.is_empty() || pubkeys.len() != messages.len() || pubkeys.len() != signatures.len() {
+        return Err(BLSError::InputLengthError);
+    }
+
+    Ok(pubkeys
+        .iter()
+        .zip(messages)
+        .zip(signatures)
+        .map(|((pubkey, message), signature)| {
+            let hash = H::try_hash_to_curve(message).ok()?;
+            let signature = G1Point(signature.to_bytes().ok()?);
+            signature.validate().ok()?;
+            Some((hash.0, pubkey.0, signature.0))
+        })
+        .collect())
+}
+
+fn bisect(
+    triples: &[Option<Triple>],
+    indices: &[usize],
+    invalid: &mut Vec<usize>,
+) -> Result<(), BLSError> {
+    if indices.is_empty() {
+        return Ok(());
+    }
+
+    match check(triples, indices) {
+        Ok(()) => Ok(()),
+        Err(BLSError::BLSVerificationError) | Err(BLSError::AltBN128PairingError)
+            if indices.len() == 1 =>
+        {
+            invalid.push(indices[0]);
+            Ok(())
+        }
+        Err(BLSError::BLSVerificationError) | Err(BLSError::AltBN128PairingError) => {
+            let (left, right) = indices.split_at(indices.len() / 2);
+            bisect(triples, left, invalid)?;
+            bisect(triples, right, invalid)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn check(triples: &[Option<Triple>], indices: &[usize]) -> Result<(), BLSError> {
+    let mut input = Vec::with_capacity((indices.len() + 1) * 192);
+    let mut signatures = Vec::with_capacity(indices.len());
+    let mut scalars = Vec::with_capacity(indices.len());
+
+    for &i in indices {
+        let (hash, pubkey, signature) = triples[i].as_ref().ok_or(BLSError::BLSVerificationError)?;
+        let scalar = random_scalar();
+
+        // 1) Pair r_i·H(m_i) with pk_i
+        input.extend_from_slice(&g1_mul(hash, &scalar)?);
+        input.extend_from_slice(pubkey);
+
+        signatures.push(G1Point(*signature));
+        scalars.push(scalar);
+    }
+
+    // 2) Pair Σ r_i·σ_i with -G2::one()
+    input.extend_from_slice(&G1Point::msm(&signatures, &scalars)?.0);
+    input.extend_from_slice(&G2_MINUS_ONE);
+
+    pairing_check(&input)
+}
+
+/// Samples a non-zero 128-bit scalar as a 32 byte big-endian integer.
+fn random_scalar() -> [u8; 32] {
+    let mut scalar = [0u8; 32];
+    while scalar[16..].iter().all(|b| *b == 0) {
+        rand::thread_rng().fill_bytes(&mut scalar[16..]);
+    }
+    scalar
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::{batch_verify, batch_verify_find_invalid};
+    use crate::{test_utils::test_triples, BLSError, G1Point, Sha256Normalized};
+
+    #[test]
+    fn batch_verify_valid() {
+        let (pubkeys, messages, signatures) = test_triples(8);
+        let messages: Vec<&[u8]> = messages.iter().map(|m| &m[..]).collect();
+
+        batch_verify::<Sha256Normalized, G1Point>(&pubkeys, &messages, &signatures)
+            .expect("Failed to batch verify");
+        assert!(batch_verify_find_invalid::<Sha256Normalized, G1Point>(&pubkeys, &messages, &signatures)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn batch_verify_identifies_invalid() {
+        let (pubkeys, messages, mut signatures) = test_triples(8);
+        let messages: Vec<&[u8]> = messages.iter().map(|m| &m[..]).collect();
+
+        // Swap two signatures so both triples become invalid
+        signatures.swap(2, 5);
+
+        assert_eq!(
+            batch_verify::<Sha256Normalized, G1Point>(&pubkeys, &messages, &signatures),
+            Err(BLSError::BLSVerificationError)
+        );
+        assert_eq!(
+            batch_verify_find_invalid::<Sha256Normalized, G1Point>(&pubkeys, &messages, &signatures)
+                .unwrap(),
+            vec![2, 5]
+        );
+    }
+}
@@ -14,6 +14,37 @@ pub static MODULUS: UBig = unsafe {
     ])
 };
 
+/// # MODULUS_BYTES
+/// The base field modulus p as 32 big-endian bytes, used to negate field elements without
+/// allocating.
+pub const MODULUS_BYTES: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// # SCALAR_ORDER
+/// This is the order r of the G1 and G2 prime-order subgroups, ie: the modulus of the scalar field Fr
+/// 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+///
+/// Valid private keys are scalars in the range [1, r)
+pub static SCALAR_ORDER: UBig = unsafe {
+    UBig::from_static_words(&[
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ])
+};
+
+/// # G1_MINUS_ONE
+/// The negated G1 generator -(1, 2), used to verify signatures in G2 against public keys in G1.
+pub const G1_MINUS_ONE: [u8; 64] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x45,
+];
+
 pub const G2_MINUS_ONE: [u8; 128] = [
     0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
     0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
@@ -24,3 +55,8 @@ pub const G2_MINUS_ONE: [u8; 128] = [
     0x1d, 0x9b, 0xef, 0xcd, 0x05, 0xa5, 0x32, 0x3e, 0x6d, 0xa4, 0xd4, 0x35, 0xf3, 0xb6, 0x17, 0xcd,
     0xb3, 0xaf, 0x83, 0x28, 0x5c, 0x2d, 0xf7, 0x11, 0xef, 0x39, 0xc0, 0x15, 0x71, 0x82, 0x7f, 0x9d,
 ];
+
+/// # POP_DOMAIN
+/// Domain separation tag for proofs of possession in schemes without native DST support, used
+/// through `PopDst` by the default `HashToCurve::try_hash_to_curve_pop`.
+pub const POP_DOMAIN: &[u8] = b"BLS_POP_ALT_BN128_G1_POP_";
@@ -0,0 +1,150 @@
+use core::{fmt, str::FromStr};
+
+use crate::{BLSError, G1CompressedPoint, G1Point, G2CompressedPoint, G2Point};
+
+/// Writes `bytes` as lowercase hex.
+fn write_hex(f: &mut fmt::Formatter, bytes: &[u8]) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+}
+
+/// Reads exactly `N` bytes from a hex string, with or without a `0x` prefix.
+fn parse_hex<const N: usize>(s: &str) -> Result<[u8; N], BLSError> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if !s.is_ascii() || s.len() != N * 2 {
+        return Err(BLSError::SerializationError);
+    }
+
+    let mut bytes = [0u8; N];
+    for (byte, chunk) in bytes.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+        // Both characters are ASCII, so the chunk is valid UTF-8
+        let digits = core::str::from_utf8(chunk).map_err(|_| BLSError::SerializationError)?;
+        *byte = u8::from_str_radix(digits, 16).map_err(|_| BLSError::SerializationError)?;
+    }
+    Ok(bytes)
+}
+
+/// Implements `Display` and `Debug` as hex, and `FromStr` from hex validated with the type's
+/// `try_from_bytes`. Like deserialization, `FromStr` accepts the identity, so every point we
+/// display parses again.
+macro_rules! impl_hex {
+    ($type:ident, $len:literal) => {
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_hex(f, &self.0)
+            }
+        }
+
+        impl fmt::Debug for $type {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(concat!(stringify!($type), "("))?;
+                write_hex(f, &self.0)?;
+                f.write_str(")")
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = BLSError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let point = $type(parse_hex::<$len>(s)?);
+                if point.is_identity() {
+                    return Ok(point);
+                }
+                $type::try_from_bytes(&point.0)
+            }
+        }
+    };
+}
+
+impl_hex!(G1Point, 64);
+impl_hex!(G1CompressedPoint, 32);
+impl_hex!(G2Point, 128);
+impl_hex!(G2CompressedPoint, 64);
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{
+        BLSError, G1CompressedPoint, G1Point, G2CompressedPoint, G2Point, PrivKey, Sha256Normalized,
+    };
+
+    #[test]
+    fn hex_roundtrip() {
+        let privkey = PrivKey::from_random();
+        let pubkey = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
+        let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+
+        let hex = pubkey.to_string();
+        assert_eq!(hex, hex::encode(pubkey.0));
+        assert_eq!(hex.parse::<G2CompressedPoint>(), Ok(pubkey));
+        assert_eq!(
+            format!("0x{}", hex).parse::<G2CompressedPoint>(),
+            Ok(pubkey)
+        );
+        assert_eq!(
+            format!("{:?}", pubkey),
+            format!("G2CompressedPoint({})", hex)
+        );
+
+        assert_eq!(signature.to_string().parse::<G1Point>(), Ok(signature));
+        assert_eq!(
+            G2Point::try_from(pubkey)
+                .unwrap()
+                .to_string()
+                .parse::<G2Point>(),
+            G2Point::try_from(pubkey)
+        );
+
+        // Malformed hex, wrong lengths and invalid points are rejected
+        assert_eq!(
+            "zz".parse::<G1CompressedPoint>(),
+            Err(BLSError::SerializationError)
+        );
+        assert_eq!(
+            hex[2..].parse::<G2CompressedPoint>(),
+            Err(BLSError::SerializationError)
+        );
+        assert_eq!(
+            format!("é{}", &hex[2..]).parse::<G2CompressedPoint>(),
+            Err(BLSError::SerializationError)
+        );
+        assert_eq!(
+            hex::encode([0xffu8; 64]).parse::<G1Point>(),
+            Err(BLSError::G1PointNotCanonicalError)
+        );
+
+        // The identity parses, so every displayed point round-trips
+        assert_eq!(
+            G1Point::identity().to_string().parse::<G1Point>(),
+            Ok(G1Point::identity())
+        );
+        assert_eq!(
+            G2CompressedPoint::identity()
+                .to_string()
+                .parse::<G2CompressedPoint>(),
+            Ok(G2CompressedPoint::identity())
+        );
+    }
+
+    #[test]
+    fn equality() {
+        let privkey = PrivKey::from_random();
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+        let compressed_pubkey = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
+        let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+        let compressed_signature = G1CompressedPoint::try_from(signature).unwrap();
+
+        // Points equal their compressed forms, but not their negations
+        assert_eq!(pubkey, compressed_pubkey);
+        assert_eq!(compressed_pubkey, pubkey);
+        assert_eq!(signature, compressed_signature);
+        assert_eq!(compressed_signature, signature);
+        assert_ne!(-pubkey, compressed_pubkey);
+        assert_ne!(-signature, compressed_signature);
+
+        let keys: HashSet<G2CompressedPoint> =
+            [compressed_pubkey, compressed_pubkey, -compressed_pubkey].into();
+        assert_eq!(keys.len(), 2);
+    }
+}
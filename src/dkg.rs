@@ -0,0 +1,438 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ark_bn254::{Fr, G2Affine, G2Projective};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{Field, UniformRand, Zero};
+use zeroize::Zeroize;
+
+use crate::{
+    errors::BLSError,
+    g2_point::{G2CompressedPoint, G2Point},
+    privkey::PrivKey,
+    threshold::{evaluate_polynomial, SecretShare},
+};
+
+/// # DKG Commitment
+///
+/// Feldman commitments `a_k·G2` to each coefficient of a dealer's secret polynomial, broadcast
+/// to all participants in the first round.
+#[derive(Clone)]
+pub struct DkgCommitment {
+    pub dealer: u32,
+    pub commitments: Vec<G2Point>,
+}
+
+/// # DKG Share
+///
+/// The evaluation `f_dealer(recipient)` of a dealer's secret polynomial. Sent privately in the
+/// first round, and revealed publicly when answering a complaint.
+pub struct DkgShare {
+    pub dealer: u32,
+    pub recipient: u32,
+    pub share: PrivKey,
+}
+
+/// # DKG Complaint
+///
+/// Broadcast by `accuser` when the share it received from `dealer` is missing or does not match
+/// the dealer's commitments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DkgComplaint {
+    pub accuser: u32,
+    pub dealer: u32,
+}
+
+/// # DKG Output
+///
+/// The result of a successful run: our share of the group private key, the group public key
+/// and the set of qualified dealers that contributed to it.
+pub struct DkgOutput {
+    pub share: SecretShare,
+    pub group_pubkey: G2CompressedPoint,
+    pub qualified: Vec<u32>,
+}
+
+/// # DKG Participant
+///
+/// A participant in a Pedersen distributed key generation, where every participant acts as a
+/// dealer of a Feldman VSS and the group key is the sum of all qualified dealers' secrets, so
+/// no single party ever learns the group private key. A run looks like:
+///
+/// 1. Broadcast `commitment()` and privately send `share_for(j)` to every other participant `j`
+/// 2. Feed everything received into `receive_commitment` and `receive_share`, broadcasting any
+///    complaints they return
+/// 3. Dealers answer each complaint against them with `respond_to_complaint`, and everyone
+///    feeds complaints and responses into `resolve_complaint`
+/// 4. Call `finalize` to get our `DkgOutput`
+pub struct DkgParticipant {
+    index: u32,
+    threshold: u32,
+    n: u32,
+    coefficients: Vec<Fr>,
+    commitments: BTreeMap<u32, Vec<G2Affine>>,
+    shares: BTreeMap<u32, Fr>,
+    disqualified: BTreeSet<u32>,
+}
+
+impl Drop for DkgParticipant {
+    fn drop(&mut self) {
+        self.coefficients.iter_mut().for_each(|c| c.zeroize());
+        self.shares.values_mut().for_each(|s| s.zeroize());
+    }
+}
+
+impl DkgParticipant {
+    /// Creates participant `index` in `1..=n` of a `threshold`-of-`n` DKG with a random secret
+    /// polynomial of degree `threshold - 1`.
+    pub fn new(index: u32, threshold: u32, n: u32) -> Result<Self, BLSError> {
+        if threshold == 0 || threshold > n || index == 0 || index > n {
+            return Err(BLSError::ThresholdError);
+        }
+
+        let coefficients: Vec<Fr> = (0..threshold)
+            .map(|_| Fr::rand(&mut rand::thread_rng()))
+            .collect();
+
+        let mut participant = Self {
+            index,
+            threshold,
+            n,
+            coefficients,
+            commitments: BTreeMap::new(),
+            shares: BTreeMap::new(),
+            disqualified: BTreeSet::new(),
+        };
+
+        // Deal to ourselves
+        let own_commitments = participant.commitment_points();
+        participant.commitments.insert(index, own_commitments);
+        let own_share = evaluate_polynomial(&participant.coefficients, Fr::from(index));
+        participant.shares.insert(index, own_share);
+
+        Ok(participant)
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    fn commitment_points(&self) -> Vec<G2Affine> {
+        let generator = G2Affine::generator();
+        G2Projective::normalize_batch(
+            &self
+                .coefficients
+                .iter()
+                .map(|c| generator * c)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Our Feldman commitments, to be broadcast to all participants.
+    pub fn commitment(&self) -> Result<DkgCommitment, BLSError> {
+        Ok(DkgCommitment {
+            dealer: self.index,
+            commitments: self
+                .commitment_points()
+                .iter()
+                .map(G2Point::from_affine)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// The share of our secret polynomial for `recipient`, to be sent privately.
+    pub fn share_for(&self, recipient: u32) -> Result<DkgShare, BLSError> {
+        if recipient == 0 || recipient > self.n {
+            return Err(BLSError::ThresholdError);
+        }
+
+        let mut share = evaluate_polynomial(&self.coefficients, Fr::from(recipient));
+        let key = PrivKey::from_scalar(&share);
+        share.zeroize();
+
+        Ok(DkgShare {
+            dealer: self.index,
+            recipient,
+            share: key?,
+        })
+    }
+
+    /// Stores a dealer's commitments, rejecting malformed commitments with `DkgError`.
+    pub fn receive_commitment(&mut self, commitment: &DkgCommitment) -> Result<(), BLSError> {
+        if commitment.dealer == 0
+            || commitment.dealer > self.n
+            || commitment.commitments.len() != self.threshold as usize
+            || self.commitments.contains_key(&commitment.dealer)
+        {
+            return Err(BLSError::DkgError);
+        }
+
+        let points = commitment
+            .commitments
+            .iter()
+            .map(|c| {
+                let point = c.to_affine()?;
+                if point.is_zero()
+                    || !point.is_on_curve()
+                    || !point.is_in_correct_subgroup_assuming_on_curve()
+                {
+                    return Err(BLSError::DkgError);
+                }
+                Ok(point)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.commitments.insert(commitment.dealer, points);
+        Ok(())
+    }
+
+    /// Verifies and stores a share sent to us, returning a complaint to broadcast if the share
+    /// does not match the dealer's commitments.
+    pub fn receive_share(&mut self, share: &DkgShare) -> Option<DkgComplaint> {
+        let complaint = DkgComplaint {
+            accuser: self.index,
+            dealer: share.dealer,
+        };
+
+        if share.recipient != self.index {
+            return Some(complaint);
+        }
+
+        match self.verify_share(share) {
+            Some(scalar) => {
+                self.shares.insert(share.dealer, scalar);
+                None
+            }
+            None => Some(complaint),
+        }
+    }
+
+    /// Returns the complaint to broadcast against `dealer` if we never received a share from it.
+    pub fn missing_share_complaint(&self, dealer: u32) -> Option<DkgComplaint> {
+        (!self.shares.contains_key(&dealer)).then_some(DkgComplaint {
+            accuser: self.index,
+            dealer,
+        })
+    }
+
+    /// Answers a complaint against us by revealing the accuser's share publicly.
+    pub fn respond_to_complaint(&self, complaint: &DkgComplaint) -> Option<DkgShare> {
+        if complaint.dealer != self.index {
+            return None;
+        }
+        self.share_for(complaint.accuser).ok()
+    }
+
+    /// Resolves a broadcast complaint given the dealer's public response, if any. The dealer is
+    /// disqualified unless it revealed a share that matches its commitments. If we are the
+    /// accuser, a valid revealed share replaces the one we complained about.
+    pub fn resolve_complaint(&mut self, complaint: &DkgComplaint, response: Option<&DkgShare>) {
+        let scalar = response
+            .filter(|r| r.dealer == complaint.dealer && r.recipient == complaint.accuser)
+            .and_then(|r| self.verify_share(r));
+
+        match scalar {
+            Some(scalar) if complaint.accuser == self.index => {
+                self.shares.insert(complaint.dealer, scalar);
+            }
+            Some(_) => {}
+            None => {
+                self.disqualified.insert(complaint.dealer);
+            }
+        }
+    }
+
+    /// Checks `share·G2 == Σ C_k·recipient^k` against the dealer's commitments.
+    fn verify_share(&self, share: &DkgShare) -> Option<Fr> {
+        let commitments = self.commitments.get(&share.dealer)?;
+        let scalar = share.share.to_scalar().ok()?;
+
+        let x = Fr::from(share.recipient);
+        let mut power = Fr::ONE;
+        let mut expected = G2Projective::zero();
+        for commitment in commitments {
+            expected += *commitment * power;
+            power *= x;
+        }
+
+        (G2Affine::generator() * scalar == expected).then_some(scalar)
+    }
+
+    /// Completes the DKG. Every dealer that committed and was not disqualified is qualified,
+    /// and we must hold a valid share from each of them.
+    pub fn finalize(&self) -> Result<DkgOutput, BLSError> {
+        let qualified: Vec<u32> = self
+            .commitments
+            .keys()
+            .filter(|dealer| !self.disqualified.contains(dealer))
+            .copied()
+            .collect();
+
+        if qualified.len() < self.threshold as usize {
+            return Err(BLSError::DkgError);
+        }
+
+        let mut secret = Fr::zero();
+        let mut group_pubkey = G2Projective::zero();
+        for dealer in qualified.iter() {
+            secret += self.shares.get(dealer).ok_or(BLSError::DkgError)?;
+            group_pubkey += self.commitments[dealer][0];
+        }
+
+        let key = PrivKey::from_scalar(&secret);
+        secret.zeroize();
+        let share = SecretShare {
+            index: self.index,
+            key: key?,
+        };
+
+        Ok(DkgOutput {
+            share,
+            group_pubkey: G2CompressedPoint::try_from(&G2Point::from_affine(
+                &group_pubkey.into_affine(),
+            )?)?,
+            qualified,
+        })
+    }
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::{DkgComplaint, DkgParticipant, DkgShare};
+    use crate::{
+        combine_partial_signatures, G1Point, G2CompressedPoint, PrivKey, Sha256Normalized,
+    };
+
+    /// Runs the first round of a DKG between `n` simulated participants, letting `tamper` modify
+    /// any share in transit, and returns the participants and all complaints.
+    fn run_round_one(
+        threshold: u32,
+        n: u32,
+        tamper: impl Fn(DkgShare) -> DkgShare,
+    ) -> (Vec<DkgParticipant>, Vec<DkgComplaint>) {
+        let mut participants: Vec<DkgParticipant> = (1..=n)
+            .map(|i| DkgParticipant::new(i, threshold, n).unwrap())
+            .collect();
+
+        let commitments: Vec<_> = participants
+            .iter()
+            .map(|p| p.commitment().unwrap())
+            .collect();
+        let mut complaints = vec![];
+
+        for dealer in 0..n as usize {
+            for recipient in 0..n as usize {
+                if dealer == recipient {
+                    continue;
+                }
+                participants[recipient]
+                    .receive_commitment(&commitments[dealer])
+                    .unwrap();
+                let share = tamper(
+                    participants[dealer]
+                        .share_for(recipient as u32 + 1)
+                        .unwrap(),
+                );
+                if let Some(complaint) = participants[recipient].receive_share(&share) {
+                    complaints.push(complaint);
+                }
+            }
+        }
+
+        (participants, complaints)
+    }
+
+    /// Returns a `tamper` function for `run_round_one` that replaces the share `dealer` sends to
+    /// `recipient` with a random key, leaving every other share intact.
+    fn corrupt_share(dealer: u32, recipient: u32) -> impl Fn(DkgShare) -> DkgShare {
+        move |share| {
+            if share.dealer == dealer && share.recipient == recipient {
+                DkgShare {
+                    share: PrivKey::from_random(),
+                    ..share
+                }
+            } else {
+                share
+            }
+        }
+    }
+
+    fn assert_threshold_signing(
+        participants: &[DkgParticipant],
+        signers: &[usize],
+    ) -> G2CompressedPoint {
+        let outputs: Vec<_> = participants.iter().map(|p| p.finalize().unwrap()).collect();
+
+        // Everyone agrees on the group public key
+        let group_pubkey = outputs[0].group_pubkey;
+        assert!(outputs.iter().all(|o| o.group_pubkey.0 == group_pubkey.0));
+
+        let partials: Vec<(u32, G1Point)> = signers
+            .iter()
+            .map(|&i| {
+                let share = &outputs[i].share;
+                (
+                    share.index,
+                    share
+                        .key
+                        .sign::<Sha256Normalized, &[u8]>(b"sample")
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        let signature = combine_partial_signatures(&partials).unwrap();
+        assert!(group_pubkey
+            .verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+
+        group_pubkey
+    }
+
+    #[test]
+    fn honest_participants() {
+        let (participants, complaints) = run_round_one(3, 5, |share| share);
+        assert!(complaints.is_empty());
+
+        assert_threshold_signing(&participants, &[0, 2, 4]);
+        assert_threshold_signing(&participants, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn complaint_answered_correctly() {
+        // Dealer 1 sends a bad share to participant 2, but reveals the correct one when accused
+        let (mut participants, complaints) = run_round_one(3, 5, corrupt_share(1, 2));
+        assert_eq!(
+            complaints,
+            vec![DkgComplaint {
+                accuser: 2,
+                dealer: 1
+            }]
+        );
+
+        let response = participants[0].respond_to_complaint(&complaints[0]);
+        for participant in participants.iter_mut() {
+            participant.resolve_complaint(&complaints[0], response.as_ref());
+        }
+
+        assert_eq!(
+            participants[1].finalize().unwrap().qualified,
+            vec![1, 2, 3, 4, 5]
+        );
+        assert_threshold_signing(&participants, &[0, 1, 4]);
+    }
+
+    #[test]
+    fn unanswered_complaint_disqualifies_dealer() {
+        let (mut participants, complaints) = run_round_one(3, 5, corrupt_share(1, 2));
+
+        for participant in participants.iter_mut() {
+            participant.resolve_complaint(&complaints[0], None);
+        }
+
+        assert_eq!(
+            participants[1].finalize().unwrap().qualified,
+            vec![2, 3, 4, 5]
+        );
+        assert_threshold_signing(&participants, &[1, 2, 3]);
+    }
+}
@@ -12,4 +12,21 @@ pub enum BLSError {
     G1PointDecompressionError,
     G2PointCompressionError,
     G2PointDecompressionError,
+    InputLengthError,
+    DuplicateMessageError,
+    KeyDerivationError,
+    KeystoreError,
+    KeystoreChecksumError,
+    ThresholdError,
+    DkgError,
+    SignerBitmapError,
+    InsufficientWeightError,
+    WeightOverflowError,
+    G1PointIdentityError,
+    G1PointNotCanonicalError,
+    G1PointNotOnCurveError,
+    G2PointIdentityError,
+    G2PointNotCanonicalError,
+    G2PointNotOnCurveError,
+    G2PointNotInSubgroupError,
 }
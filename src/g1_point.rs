@@ -1,19 +1,88 @@
-use core::ops::Add;
-use num::CheckedAdd;
+use core::{
+    iter::Sum,
+    ops::{Add, Neg, Sub},
+};
+use num::{CheckedAdd, CheckedSub};
+use zeroize::Zeroize;
+#[cfg(not(target_os = "solana"))]
+use ark_bn254::{G1Affine, G1Projective};
+#[cfg(not(target_os = "solana"))]
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+#[cfg(not(target_os = "solana"))]
+use ark_ff::BigInt;
+#[cfg(not(target_os = "solana"))]
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
 use solana_bn254::{
     compression::prelude::{alt_bn128_g1_compress, alt_bn128_g1_decompress},
     prelude::{alt_bn128_addition, alt_bn128_multiplication},
 };
 
-use crate::{BLSError, BLSSignature, PrivKey};
+use crate::{
+    g2_arithmetic::Fq,
+    g2_point::{pairing_check, G2Point},
+    BLSError, BLSSignature, BLSSignatureG2, HashToCurveG2, PrivKey, G1_MINUS_ONE, MODULUS_BYTES,
+};
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
 pub struct G1Point(pub [u8; 64]);
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
 pub struct G1CompressedPoint(pub [u8; 32]);
 
+/// Multiplies a G1 point by a 32 byte big-endian scalar using `alt_bn128_multiplication`.
+pub(crate) fn g1_mul(point: &[u8; 64], scalar: &[u8; 32]) -> Result<[u8; 64], BLSError> {
+    let mut result = [0u8; 64];
+    result.clone_from_slice(
+        &alt_bn128_multiplication(&[&point[..], &scalar[..]].concat())
+            .map_err(|_| BLSError::AltBN128MulError)?,
+    );
+    Ok(result)
+}
+
+/// Converts a 32 byte big-endian scalar into arkworks' little-endian limbs.
+#[cfg(not(target_os = "solana"))]
+pub(crate) fn bigint_from_be_bytes(scalar: &[u8; 32]) -> BigInt<4> {
+    let mut limbs = [0u64; 4];
+    for (i, chunk) in scalar.chunks_exact(8).enumerate() {
+        limbs[3 - i] = u64::from_be_bytes(chunk.try_into().unwrap());
+    }
+    BigInt::new(limbs)
+}
+
+/// Negates a 32 byte big-endian base field element, ie: returns `p - element`, mapping zero to
+/// itself. The element must be reduced.
+pub(crate) fn negate_fq(element: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    if element.iter().all(|&b| b == 0) {
+        return result;
+    }
+
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let diff = MODULUS_BYTES[i] as i16 - element[i] as i16 - borrow;
+        borrow = (diff < 0) as i16;
+        result[i] = diff.rem_euclid(256) as u8;
+    }
+    result
+}
+
+/// Flips the sign of y in a compressed point by toggling the flag in the most significant bit,
+/// leaving the identity unchanged.
+pub(crate) fn negate_compressed<const N: usize>(point: &[u8; N]) -> [u8; N] {
+    let mut result = *point;
+    if result != [0u8; N] {
+        result[0] ^= 0x80;
+    }
+    result
+}
+
 impl BLSSignature for G1Point {
     fn to_bytes(&self) -> Result<[u8; 64], BLSError> {
         Ok(self.0)
@@ -26,11 +95,245 @@ impl BLSSignature for G1CompressedPoint {
     }
 }
 
+impl G1Point {
+    /// # Identity
+    ///
+    /// The point at infinity, encoded as all zeros.
+    pub const fn identity() -> G1Point {
+        G1Point([0u8; 64])
+    }
+
+    /// # Is Identity
+    ///
+    /// Returns whether this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 64]
+    }
+
+    /// Converts from the big-endian `(x, y)` syscall encoding into an arkworks affine point,
+    /// rejecting points that fail `validate` other than the identity.
+    #[cfg(not(target_os = "solana"))]
+    pub(crate) fn to_affine(self) -> Result<G1Affine, BLSError> {
+        if self.0 == [0u8; 64] {
+            return Ok(G1Affine::zero());
+        }
+
+        self.validate()?;
+
+        let mut bytes = self.0;
+        bytes[..32].reverse();
+        bytes[32..].reverse();
+
+        G1Affine::deserialize_uncompressed_unchecked(&bytes[..])
+            .map_err(|_| BLSError::SerializationError)
+    }
+
+    /// Converts an arkworks affine point into the big-endian syscall encoding.
+    #[cfg(not(target_os = "solana"))]
+    pub(crate) fn from_affine(point: &G1Affine) -> Result<Self, BLSError> {
+        if point.is_zero() {
+            return Ok(G1Point([0u8; 64]));
+        }
+
+        // Serialize the coordinates individually, as arkworks packs the sign of y into the
+        // uncompressed encoding of the point
+        let mut bytes = [0u8; 64];
+        point
+            .x
+            .serialize_uncompressed(&mut &mut bytes[..32])
+            .map_err(|_| BLSError::SerializationError)?;
+        point
+            .y
+            .serialize_uncompressed(&mut &mut bytes[32..])
+            .map_err(|_| BLSError::SerializationError)?;
+
+        bytes[..32].reverse();
+        bytes[32..].reverse();
+
+        Ok(G1Point(bytes))
+    }
+
+    /// # Validate
+    ///
+    /// Checks the point is a valid signature: not the identity, with coordinates reduced modulo
+    /// p and on the curve `y² = x³ + 3`. G1 has cofactor one, so every point on the curve is in
+    /// the prime order subgroup.
+    pub fn validate(&self) -> Result<(), BLSError> {
+        if self.0 == [0u8; 64] {
+            return Err(BLSError::G1PointIdentityError);
+        }
+
+        let (Some(x), Some(y)) = (
+            Fq::from_be_bytes(&self.0[..32]),
+            Fq::from_be_bytes(&self.0[32..]),
+        ) else {
+            return Err(BLSError::G1PointNotCanonicalError);
+        };
+
+        let b = Fq::ONE.double().add(&Fq::ONE);
+        if !y.square().sub(&x.square().mul(&x)).sub(&b).is_zero() {
+            return Err(BLSError::G1PointNotOnCurveError);
+        }
+
+        Ok(())
+    }
+
+    /// # Try From Bytes
+    ///
+    /// Reads a point in the 64 byte syscall encoding, rejecting it unless it passes `validate`.
+    pub fn try_from_bytes(bytes: &[u8; 64]) -> Result<G1Point, BLSError> {
+        let point = G1Point(*bytes);
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// # Mul Scalar
+    ///
+    /// Multiplies the point by a 32 byte big-endian scalar using `alt_bn128_multiplication`.
+    pub fn mul_scalar(&self, scalar: &[u8; 32]) -> Result<G1Point, BLSError> {
+        Ok(G1Point(g1_mul(&self.0, scalar)?))
+    }
+
+    /// # MSM
+    ///
+    /// Computes the multi-scalar multiplication `Σ scalars[i]·points[i]` for 32 byte big-endian
+    /// scalars. Off-chain this uses arkworks' Pippenger implementation, while on-chain it
+    /// composes `alt_bn128_multiplication` and `alt_bn128_addition`, skipping terms that are
+    /// zero and multiplications by one.
+    pub fn msm(points: &[G1Point], scalars: &[[u8; 32]]) -> Result<G1Point, BLSError> {
+        if points.len() != scalars.len() {
+            return Err(BLSError::InputLengthError);
+        }
+
+        #[cfg(not(target_os = "solana"))]
+        {
+            let bases = points
+                .iter()
+                .copied()
+                .map(G1Point::to_affine)
+                .collect::<Result<Vec<_>, _>>()?;
+            let bigints: Vec<BigInt<4>> = scalars.iter().map(bigint_from_be_bytes).collect();
+
+            G1Point::from_affine(&G1Projective::msm_bigint(&bases, &bigints).into_affine())
+        }
+
+        #[cfg(target_os = "solana")]
+        {
+            let mut one = [0u8; 32];
+            one[31] = 1;
+
+            let mut result = [0u8; 64];
+            for (point, scalar) in points.iter().zip(scalars) {
+                if point.0 == [0u8; 64] || scalar == &[0u8; 32] {
+                    continue;
+                }
+
+                let term = if scalar == &one {
+                    point.0
+                } else {
+                    g1_mul(&point.0, scalar)?
+                };
+
+                if result == [0u8; 64] {
+                    result = term;
+                } else {
+                    let sum = alt_bn128_addition(&[&result[..], &term[..]].concat())
+                        .map_err(|_| BLSError::AltBN128AddError)?;
+                    result.clone_from_slice(&sum);
+                }
+            }
+            Ok(G1Point(result))
+        }
+    }
+
+    /// # Verify Signature
+    ///
+    /// Verifies a signature in G2 against this public key in G1, for use with the minimal public
+    /// key size variant where signatures are produced by `PrivKey::sign_g2`. Both the public key
+    /// and the signature are validated first, so the identity pair can't verify any message and
+    /// signatures outside the prime order subgroup never reach the pairing.
+    pub fn verify_signature<H: HashToCurveG2, T: AsRef<[u8]>, S: BLSSignatureG2>(
+        self,
+        signature: S,
+        message: T,
+    ) -> Result<(), BLSError> {
+        self.validate()?;
+        let signature = G2Point(signature.to_bytes()?);
+        signature.validate()?;
+
+        let mut input = [0u8; 384];
+
+        // 1) Our public key
+        input[..64].clone_from_slice(&self.0);
+        // 2) Hash message to curve
+        input[64..192].clone_from_slice(&H::try_hash_to_curve_g2(message)?.0);
+        // 3) Pair -G1::one() with our signature
+        input[192..256].clone_from_slice(&G1_MINUS_ONE);
+        input[256..].clone_from_slice(&signature.0);
+
+        // Calculate result
+        pairing_check(&input)
+    }
+}
+
+impl G1CompressedPoint {
+    /// # Identity
+    ///
+    /// The point at infinity, encoded as all zeros.
+    pub const fn identity() -> G1CompressedPoint {
+        G1CompressedPoint([0u8; 32])
+    }
+
+    /// # Is Identity
+    ///
+    /// Returns whether this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 32]
+    }
+
+    /// # Validate
+    ///
+    /// Decompresses the point and checks it passes `G1Point::validate`.
+    pub fn validate(&self) -> Result<(), BLSError> {
+        if self.0 == [0u8; 32] {
+            return Err(BLSError::G1PointIdentityError);
+        }
+        G1Point::try_from(self)?.validate()
+    }
+
+    /// # Try From Bytes
+    ///
+    /// Reads a compressed point, rejecting it unless it passes `validate`.
+    pub fn try_from_bytes(bytes: &[u8; 32]) -> Result<G1CompressedPoint, BLSError> {
+        let point = G1CompressedPoint(*bytes);
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// # Mul Scalar
+    ///
+    /// Multiplies the compressed point by a 32 byte big-endian scalar.
+    pub fn mul_scalar(&self, scalar: &[u8; 32]) -> Result<G1CompressedPoint, BLSError> {
+        G1CompressedPoint::try_from(G1Point::try_from(self)?.mul_scalar(scalar)?)
+    }
+
+    /// # Verify Signature
+    ///
+    /// Verifies a signature in G2 against this compressed public key in G1.
+    pub fn verify_signature<H: HashToCurveG2, T: AsRef<[u8]>, S: BLSSignatureG2>(
+        self,
+        signature: S,
+        message: T,
+    ) -> Result<(), BLSError> {
+        G1Point::try_from(&self)?.verify_signature::<H, T, S>(signature, message)
+    }
+}
+
 impl Add for G1Point {
     type Output = G1Point;
 
     fn add(self, rhs: Self) -> G1Point {
-        self.checked_add(&rhs).expect("G2Point addition failed")
+        self.checked_add(&rhs).expect("G1Point addition failed")
     }
 }
 
@@ -55,11 +358,92 @@ impl CheckedAdd for G1Point {
     }
 }
 
+/// Sums points with one `alt_bn128_addition` per point, starting from the identity.
+impl Sum for G1Point {
+    fn sum<I: Iterator<Item = G1Point>>(iter: I) -> G1Point {
+        iter.fold(G1Point::identity(), |acc, point| acc + point)
+    }
+}
+
+impl<'a> Sum<&'a G1Point> for G1Point {
+    fn sum<I: Iterator<Item = &'a G1Point>>(iter: I) -> G1Point {
+        iter.copied().sum()
+    }
+}
+
+impl Sum for G1CompressedPoint {
+    fn sum<I: Iterator<Item = G1CompressedPoint>>(iter: I) -> G1CompressedPoint {
+        let sum: G1Point = iter
+            .map(|point| G1Point::try_from(&point).expect("G1Point decompression failed"))
+            .sum();
+        G1CompressedPoint::try_from(sum).expect("G1Point compression failed")
+    }
+}
+
+impl<'a> Sum<&'a G1CompressedPoint> for G1CompressedPoint {
+    fn sum<I: Iterator<Item = &'a G1CompressedPoint>>(iter: I) -> G1CompressedPoint {
+        iter.copied().sum()
+    }
+}
+
+impl Neg for G1Point {
+    type Output = G1Point;
+
+    fn neg(self) -> G1Point {
+        let mut result = self.0;
+        result[32..].clone_from_slice(&negate_fq(&self.0[32..]));
+        G1Point(result)
+    }
+}
+
+impl Sub for G1Point {
+    type Output = G1Point;
+
+    fn sub(self, rhs: Self) -> G1Point {
+        self.checked_sub(&rhs).expect("G1Point subtraction failed")
+    }
+}
+
+impl CheckedSub for G1Point {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&-*rhs)
+    }
+}
+
+impl Neg for G1CompressedPoint {
+    type Output = G1CompressedPoint;
+
+    fn neg(self) -> G1CompressedPoint {
+        G1CompressedPoint(negate_compressed(&self.0))
+    }
+}
+
+impl Sub for G1CompressedPoint {
+    type Output = G1CompressedPoint;
+
+    fn sub(self, rhs: Self) -> G1CompressedPoint {
+        self.checked_sub(&rhs).expect("G1CompressedPoint subtraction failed")
+    }
+}
+
+impl CheckedSub for G1CompressedPoint {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let result = (|| -> Result<Self, BLSError> {
+            let difference = G1Point::try_from(self)?
+                .checked_sub(&G1Point::try_from(rhs)?)
+                .ok_or(BLSError::AltBN128AddError)?;
+            G1CompressedPoint::try_from(difference)
+        })();
+
+        result.ok()
+    }
+}
+
 impl TryFrom<PrivKey> for G1CompressedPoint {
     type Error = BLSError;
 
     fn try_from(value: PrivKey) -> Result<Self, Self::Error> {
-        let input = [
+        let mut input = [
             0x00,
             0x00,
             0x00,
@@ -124,45 +508,46 @@ impl TryFrom<PrivKey> for G1CompressedPoint {
             0x00,
             0x00,
             0x02,
-            value.0[0],
-            value.0[1],
-            value.0[2],
-            value.0[3],
-            value.0[4],
-            value.0[5],
-            value.0[6],
-            value.0[7],
-            value.0[8],
-            value.0[9],
-            value.0[10],
-            value.0[11],
-            value.0[12],
-            value.0[13],
-            value.0[14],
-            value.0[15],
-            value.0[16],
-            value.0[17],
-            value.0[18],
-            value.0[19],
-            value.0[20],
-            value.0[21],
-            value.0[22],
-            value.0[23],
-            value.0[24],
-            value.0[25],
-            value.0[26],
-            value.0[27],
-            value.0[28],
-            value.0[29],
-            value.0[30],
-            value.0[31],
+            value.as_bytes()[0],
+            value.as_bytes()[1],
+            value.as_bytes()[2],
+            value.as_bytes()[3],
+            value.as_bytes()[4],
+            value.as_bytes()[5],
+            value.as_bytes()[6],
+            value.as_bytes()[7],
+            value.as_bytes()[8],
+            value.as_bytes()[9],
+            value.as_bytes()[10],
+            value.as_bytes()[11],
+            value.as_bytes()[12],
+            value.as_bytes()[13],
+            value.as_bytes()[14],
+            value.as_bytes()[15],
+            value.as_bytes()[16],
+            value.as_bytes()[17],
+            value.as_bytes()[18],
+            value.as_bytes()[19],
+            value.as_bytes()[20],
+            value.as_bytes()[21],
+            value.as_bytes()[22],
+            value.as_bytes()[23],
+            value.as_bytes()[24],
+            value.as_bytes()[25],
+            value.as_bytes()[26],
+            value.as_bytes()[27],
+            value.as_bytes()[28],
+            value.as_bytes()[29],
+            value.as_bytes()[30],
+            value.as_bytes()[31],
         ];
 
+        let result = alt_bn128_multiplication(&input);
+        input.zeroize();
+
         let mut g1_sol_uncompressed = [0; 64];
 
-        g1_sol_uncompressed.clone_from_slice(
-            &alt_bn128_multiplication(&input).map_err(|_| BLSError::AltBN128MulError)?,
-        );
+        g1_sol_uncompressed.clone_from_slice(&result.map_err(|_| BLSError::AltBN128MulError)?);
         let compressed =
             alt_bn128_g1_compress(&g1_sol_uncompressed).map_err(|_| BLSError::SecretKeyError)?;
         Ok(G1CompressedPoint(compressed))
@@ -173,7 +558,7 @@ impl TryFrom<PrivKey> for G1Point {
     type Error = BLSError;
 
     fn try_from(value: PrivKey) -> Result<Self, Self::Error> {
-        let input = [
+        let mut input = [
             0x00,
             0x00,
             0x00,
@@ -238,49 +623,63 @@ impl TryFrom<PrivKey> for G1Point {
             0x00,
             0x00,
             0x02,
-            value.0[0],
-            value.0[1],
-            value.0[2],
-            value.0[3],
-            value.0[4],
-            value.0[5],
-            value.0[6],
-            value.0[7],
-            value.0[8],
-            value.0[9],
-            value.0[10],
-            value.0[11],
-            value.0[12],
-            value.0[13],
-            value.0[14],
-            value.0[15],
-            value.0[16],
-            value.0[17],
-            value.0[18],
-            value.0[19],
-            value.0[20],
-            value.0[21],
-            value.0[22],
-            value.0[23],
-            value.0[24],
-            value.0[25],
-            value.0[26],
-            value.0[27],
-            value.0[28],
-            value.0[29],
-            value.0[30],
-            value.0[31],
+            value.as_bytes()[0],
+            value.as_bytes()[1],
+            value.as_bytes()[2],
+            value.as_bytes()[3],
+            value.as_bytes()[4],
+            value.as_bytes()[5],
+            value.as_bytes()[6],
+            value.as_bytes()[7],
+            value.as_bytes()[8],
+            value.as_bytes()[9],
+            value.as_bytes()[10],
+            value.as_bytes()[11],
+            value.as_bytes()[12],
+            value.as_bytes()[13],
+            value.as_bytes()[14],
+            value.as_bytes()[15],
+            value.as_bytes()[16],
+            value.as_bytes()[17],
+            value.as_bytes()[18],
+            value.as_bytes()[19],
+            value.as_bytes()[20],
+            value.as_bytes()[21],
+            value.as_bytes()[22],
+            value.as_bytes()[23],
+            value.as_bytes()[24],
+            value.as_bytes()[25],
+            value.as_bytes()[26],
+            value.as_bytes()[27],
+            value.as_bytes()[28],
+            value.as_bytes()[29],
+            value.as_bytes()[30],
+            value.as_bytes()[31],
         ];
 
+        let result = alt_bn128_multiplication(&input);
+        input.zeroize();
+
         let mut g1_sol_uncompressed = [0; 64];
 
-        g1_sol_uncompressed.clone_from_slice(
-            &alt_bn128_multiplication(&input).map_err(|_| BLSError::SecretKeyError)?,
-        );
+        g1_sol_uncompressed.clone_from_slice(&result.map_err(|_| BLSError::SecretKeyError)?);
         Ok(G1Point(g1_sol_uncompressed))
     }
 }
 
+/// A point equals its compressed form if compressing it yields the same bytes.
+impl PartialEq<G1CompressedPoint> for G1Point {
+    fn eq(&self, other: &G1CompressedPoint) -> bool {
+        alt_bn128_g1_compress(&self.0).is_ok_and(|compressed| compressed == other.0)
+    }
+}
+
+impl PartialEq<G1Point> for G1CompressedPoint {
+    fn eq(&self, other: &G1Point) -> bool {
+        other == self
+    }
+}
+
 impl TryFrom<G1Point> for G1CompressedPoint {
     type Error = BLSError;
 
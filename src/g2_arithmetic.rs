@@ -0,0 +1,663 @@
+use crate::BLSError;
+
+/// Base field modulus p as little-endian 64 bit limbs.
+const P: [u64; 4] = [
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// -p⁻¹ mod 2⁶⁴, used for Montgomery reduction.
+const INV: u64 = 0x87d20782e4866389;
+
+/// R mod p where R = 2²⁵⁶, ie: one in Montgomery form.
+const R: [u64; 4] = [
+    0xd35d438dc58f0d9d,
+    0x0a78eb28f5c70b3d,
+    0x666ea36f7879462c,
+    0x0e0a77c19a07df2f,
+];
+
+/// R² mod p, used to convert into Montgomery form.
+const R2: [u64; 4] = [
+    0xf32cfc5b538afa89,
+    0xb5e71911d44501fb,
+    0x47ab1eff0a417ff6,
+    0x06d89f71cab8351f,
+];
+
+/// The twist coefficient b' = 3 / (9 + u), encoded as `c1 || c0` in big-endian.
+const TWIST_B: [u8; 64] = [
+    0x00, 0x97, 0x13, 0xb0, 0x3a, 0xf0, 0xfe, 0xd4, 0xcd, 0x2c, 0xaf, 0xad, 0xee, 0xd8, 0xfd, 0xf4,
+    0xa7, 0x4f, 0xa0, 0x84, 0xe5, 0x2d, 0x18, 0x52, 0xe4, 0xa2, 0xbd, 0x06, 0x85, 0xc3, 0x15, 0xd2,
+    0x2b, 0x14, 0x9d, 0x40, 0xce, 0xb8, 0xaa, 0xae, 0x81, 0xbe, 0x18, 0x99, 0x1b, 0xe0, 0x6a, 0xc3,
+    0xb5, 0xb4, 0xc5, 0xe5, 0x59, 0xdb, 0xef, 0xa3, 0x32, 0x67, 0xe6, 0xdc, 0x24, 0xa1, 0x38, 0xe5,
+];
+
+/// Coefficient applied to the conjugated x coordinate by the endomorphism ψ, encoded as `c1 || c0`
+/// in big-endian.
+const PSI_X: [u8; 64] = [
+    0x16, 0xc9, 0xe5, 0x50, 0x61, 0xeb, 0xae, 0x20, 0x4b, 0xa4, 0xcc, 0x8b, 0xd7, 0x5a, 0x07, 0x94,
+    0x32, 0xae, 0x2a, 0x1d, 0x0b, 0x7c, 0x9d, 0xce, 0x16, 0x65, 0xd5, 0x1c, 0x64, 0x0f, 0xcb, 0xa2,
+    0x2f, 0xb3, 0x47, 0x98, 0x4f, 0x79, 0x11, 0xf7, 0x4c, 0x0b, 0xec, 0x3c, 0xf5, 0x59, 0xb1, 0x43,
+    0xb7, 0x8c, 0xc3, 0x10, 0xc2, 0xc3, 0x33, 0x0c, 0x99, 0xe3, 0x95, 0x57, 0x17, 0x6f, 0x55, 0x3d,
+];
+
+/// Coefficient applied to the conjugated y coordinate by the endomorphism ψ, encoded as `c1 || c0`
+/// in big-endian.
+const PSI_Y: [u8; 64] = [
+    0x07, 0xc0, 0x3c, 0xbc, 0xac, 0x41, 0x04, 0x9a, 0x07, 0x04, 0xb5, 0xa7, 0xec, 0x79, 0x6f, 0x2b,
+    0x21, 0x80, 0x7d, 0xc9, 0x8f, 0xa2, 0x5b, 0xd2, 0x82, 0xd3, 0x7f, 0x63, 0x26, 0x23, 0xb0, 0xe3,
+    0x06, 0x3c, 0xf3, 0x05, 0x48, 0x9a, 0xf5, 0xdc, 0xdc, 0x5e, 0xc6, 0x98, 0xb6, 0xe2, 0xf9, 0xb9,
+    0xdb, 0xaa, 0xe0, 0xed, 0xa9, 0xc9, 0x59, 0x98, 0xdc, 0x54, 0x01, 0x46, 0x71, 0xa0, 0x13, 0x5a,
+];
+
+/// 6x² where x = 4965661367192848881 is the BN254 curve parameter, as little-endian limbs.
+const SIX_X_SQUARED: [u64; 2] = [0xf83e9682e87cfd46, 0x6f4d8248eeb859fb];
+
+/// # Fq
+///
+/// An element of the BN254 base field in Montgomery form, implemented with plain `u64` limbs so
+/// it stays cheap in SBF where arkworks' generic field arithmetic is expensive.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Fq([u64; 4]);
+
+impl Fq {
+    pub(crate) const ZERO: Fq = Fq([0; 4]);
+    pub(crate) const ONE: Fq = Fq(R);
+
+    /// Reads a 32 byte big-endian element, rejecting values that are not reduced modulo p.
+    pub(crate) fn from_be_bytes(bytes: &[u8]) -> Option<Fq> {
+        let mut limbs = [0u64; 4];
+        for (i, chunk) in bytes.chunks_exact(8).enumerate() {
+            limbs[3 - i] = u64::from_be_bytes(chunk.try_into().ok()?);
+        }
+
+        if !less_than(&limbs, &P) {
+            return None;
+        }
+
+        Some(Fq(limbs).mul(&Fq(R2)))
+    }
+
+    /// Writes the element as 32 big-endian bytes.
+    pub(crate) fn to_be_bytes(self) -> [u8; 32] {
+        let limbs = self.mul(&Fq([1, 0, 0, 0])).0;
+
+        let mut bytes = [0u8; 32];
+        for (i, chunk) in bytes.chunks_exact_mut(8).enumerate() {
+            chunk.copy_from_slice(&limbs[3 - i].to_be_bytes());
+        }
+        bytes
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    pub(crate) fn add(&self, rhs: &Fq) -> Fq {
+        let mut result = [0u64; 4];
+        let mut carry = 0u64;
+        for (r, (a, b)) in result.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            let sum = *a as u128 + *b as u128 + carry as u128;
+            *r = sum as u64;
+            carry = (sum >> 64) as u64;
+        }
+
+        // p < 2²⁵⁴ so the sum never overflows 256 bits
+        if !less_than(&result, &P) {
+            result = sub_limbs(&result, &P).0;
+        }
+        Fq(result)
+    }
+
+    pub(crate) fn sub(&self, rhs: &Fq) -> Fq {
+        let (result, borrow) = sub_limbs(&self.0, &rhs.0);
+        if borrow {
+            let mut wrapped = [0u64; 4];
+            let mut carry = 0u64;
+            for (w, (r, p)) in wrapped.iter_mut().zip(result.iter().zip(P.iter())) {
+                let sum = *r as u128 + *p as u128 + carry as u128;
+                *w = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            return Fq(wrapped);
+        }
+        Fq(result)
+    }
+
+    pub(crate) fn double(&self) -> Fq {
+        self.add(self)
+    }
+
+    pub(crate) fn neg(&self) -> Fq {
+        Fq::ZERO.sub(self)
+    }
+
+    /// Montgomery multiplication using the coarsely integrated operand scanning method.
+    pub(crate) fn mul(&self, rhs: &Fq) -> Fq {
+        let mut t = [0u64; 6];
+
+        for b in rhs.0.iter() {
+            let mut carry = 0u64;
+            for (t_j, a) in t.iter_mut().zip(self.0.iter()) {
+                let product = *t_j as u128 + *a as u128 * *b as u128 + carry as u128;
+                *t_j = product as u64;
+                carry = (product >> 64) as u64;
+            }
+            let sum = t[4] as u128 + carry as u128;
+            t[4] = sum as u64;
+            t[5] = (sum >> 64) as u64;
+
+            let m = t[0].wrapping_mul(INV);
+            let mut carry = ((t[0] as u128 + m as u128 * P[0] as u128) >> 64) as u64;
+            for j in 1..4 {
+                let product = t[j] as u128 + m as u128 * P[j] as u128 + carry as u128;
+                t[j - 1] = product as u64;
+                carry = (product >> 64) as u64;
+            }
+            let sum = t[4] as u128 + carry as u128;
+            t[3] = sum as u64;
+            t[4] = t[5] + (sum >> 64) as u64;
+        }
+
+        let mut result = [t[0], t[1], t[2], t[3]];
+        if t[4] != 0 || !less_than(&result, &P) {
+            result = sub_limbs(&result, &P).0;
+        }
+        Fq(result)
+    }
+
+    pub(crate) fn square(&self) -> Fq {
+        self.mul(self)
+    }
+
+    /// Inverts the element as a^(p - 2), returning zero for zero.
+    pub(crate) fn inverse(&self) -> Fq {
+        let exponent = sub_limbs(&P, &[2, 0, 0, 0]).0;
+
+        let mut result = Fq::ONE;
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Subtracts little-endian limbs, returning the wrapped result and whether it borrowed.
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut result = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        result[i] = diff;
+        borrow = b1 || b2;
+    }
+    (result, borrow)
+}
+
+fn less_than(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    sub_limbs(a, b).1
+}
+
+/// # Fq2
+///
+/// An element `c0 + c1·u` of the quadratic extension Fq[u] / (u² + 1).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Fq2 {
+    pub(crate) c0: Fq,
+    pub(crate) c1: Fq,
+}
+
+impl Fq2 {
+    pub(crate) const ZERO: Fq2 = Fq2 {
+        c0: Fq::ZERO,
+        c1: Fq::ZERO,
+    };
+    pub(crate) const ONE: Fq2 = Fq2 {
+        c0: Fq::ONE,
+        c1: Fq::ZERO,
+    };
+
+    /// Reads an element in the syscall encoding, ie: 32 big-endian bytes of `c1` followed by 32
+    /// big-endian bytes of `c0`.
+    pub(crate) fn from_be_bytes(bytes: &[u8]) -> Option<Fq2> {
+        Some(Fq2 {
+            c0: Fq::from_be_bytes(&bytes[32..64])?,
+            c1: Fq::from_be_bytes(&bytes[..32])?,
+        })
+    }
+
+    pub(crate) fn to_be_bytes(self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.c1.to_be_bytes());
+        bytes[32..].copy_from_slice(&self.c0.to_be_bytes());
+        bytes
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    pub(crate) fn add(&self, rhs: &Fq2) -> Fq2 {
+        Fq2 {
+            c0: self.c0.add(&rhs.c0),
+            c1: self.c1.add(&rhs.c1),
+        }
+    }
+
+    pub(crate) fn sub(&self, rhs: &Fq2) -> Fq2 {
+        Fq2 {
+            c0: self.c0.sub(&rhs.c0),
+            c1: self.c1.sub(&rhs.c1),
+        }
+    }
+
+    pub(crate) fn double(&self) -> Fq2 {
+        self.add(self)
+    }
+
+    /// Karatsuba multiplication, using three base field multiplications.
+    pub(crate) fn mul(&self, rhs: &Fq2) -> Fq2 {
+        let a0b0 = self.c0.mul(&rhs.c0);
+        let a1b1 = self.c1.mul(&rhs.c1);
+        let cross = self.c0.add(&self.c1).mul(&rhs.c0.add(&rhs.c1));
+
+        Fq2 {
+            c0: a0b0.sub(&a1b1),
+            c1: cross.sub(&a0b0).sub(&a1b1),
+        }
+    }
+
+    /// Complex squaring, using two base field multiplications.
+    pub(crate) fn square(&self) -> Fq2 {
+        let c0c1 = self.c0.mul(&self.c1);
+
+        Fq2 {
+            c0: self.c0.add(&self.c1).mul(&self.c0.sub(&self.c1)),
+            c1: c0c1.double(),
+        }
+    }
+
+    /// Inverts the element as (c0 - c1·u) / (c0² + c1²), returning zero for zero.
+    pub(crate) fn inverse(&self) -> Fq2 {
+        let norm_inverse = self.c0.square().add(&self.c1.square()).inverse();
+
+        Fq2 {
+            c0: self.c0.mul(&norm_inverse),
+            c1: self.c1.mul(&norm_inverse).neg(),
+        }
+    }
+
+    /// Maps c0 + c1·u to c0 - c1·u, ie: the p-power Frobenius map.
+    pub(crate) fn conjugate(&self) -> Fq2 {
+        Fq2 {
+            c0: self.c0,
+            c1: self.c1.neg(),
+        }
+    }
+}
+
+/// # G2 Jacobian
+///
+/// A point `(X / Z², Y / Z³)` on the G2 twist `y² = x³ + 3 / (9 + u)`, with `Z = 0` for the
+/// identity. Additions and doublings need no inversions, so summing any number of points costs
+/// a single inversion when converting back to affine coordinates.
+#[derive(Clone, Copy)]
+pub(crate) struct G2Jacobian {
+    x: Fq2,
+    y: Fq2,
+    z: Fq2,
+}
+
+impl G2Jacobian {
+    pub(crate) const IDENTITY: G2Jacobian = G2Jacobian {
+        x: Fq2::ONE,
+        y: Fq2::ONE,
+        z: Fq2::ZERO,
+    };
+
+    /// Reads an affine point in the 128 byte syscall encoding, with all zeros as the identity.
+    /// Coordinates must be reduced and the point must be on the twist, but it is not checked to
+    /// be in the prime order subgroup.
+    pub(crate) fn from_bytes(bytes: &[u8; 128]) -> Option<G2Jacobian> {
+        let point = G2Jacobian::from_bytes_unchecked(bytes)?;
+        (point.is_identity() || point.is_on_curve()).then_some(point)
+    }
+
+    /// Same as `from_bytes`, without checking the point is on the twist.
+    fn from_bytes_unchecked(bytes: &[u8; 128]) -> Option<G2Jacobian> {
+        if bytes == &[0u8; 128] {
+            return Some(G2Jacobian::IDENTITY);
+        }
+
+        Some(G2Jacobian {
+            x: Fq2::from_be_bytes(&bytes[..64])?,
+            y: Fq2::from_be_bytes(&bytes[64..])?,
+            z: Fq2::ONE,
+        })
+    }
+
+    /// Converts back to affine coordinates in the 128 byte syscall encoding.
+    pub(crate) fn to_bytes(self) -> [u8; 128] {
+        let mut bytes = [0u8; 128];
+        if self.is_identity() {
+            return bytes;
+        }
+
+        let z_inverse = self.z.inverse();
+        let z_inverse_squared = z_inverse.square();
+
+        bytes[..64].copy_from_slice(&self.x.mul(&z_inverse_squared).to_be_bytes());
+        bytes[64..].copy_from_slice(&self.y.mul(&z_inverse_squared).mul(&z_inverse).to_be_bytes());
+        bytes
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Doubles the point using the `dbl-2009-l` formulas for a = 0.
+    pub(crate) fn double(&self) -> G2Jacobian {
+        if self.is_identity() {
+            return *self;
+        }
+
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = self.x.add(&b).square().sub(&a).sub(&c).double();
+        let e = a.double().add(&a);
+        let f = e.square();
+
+        let x = f.sub(&d.double());
+        let y = e.mul(&d.sub(&x)).sub(&c.double().double().double());
+        let z = self.y.mul(&self.z).double();
+
+        G2Jacobian { x, y, z }
+    }
+
+    /// Adds a point with `Z = 1` using the `madd-2007-bl` formulas.
+    pub(crate) fn add_mixed(&self, rhs: &G2Jacobian) -> G2Jacobian {
+        if rhs.is_identity() {
+            return *self;
+        }
+        if self.is_identity() {
+            return *rhs;
+        }
+
+        let z1z1 = self.z.square();
+        let u2 = rhs.x.mul(&z1z1);
+        let s2 = rhs.y.mul(&self.z).mul(&z1z1);
+        let h = u2.sub(&self.x);
+        let r = s2.sub(&self.y).double();
+
+        if h.is_zero() {
+            return if r.is_zero() {
+                self.double()
+            } else {
+                G2Jacobian::IDENTITY
+            };
+        }
+
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h.mul(&i);
+        let v = self.x.mul(&i);
+
+        let x = r.square().sub(&j).sub(&v.double());
+        let y = r.mul(&v.sub(&x)).sub(&self.y.mul(&j).double());
+        let z = self.z.add(&h).square().sub(&z1z1).sub(&hh);
+
+        G2Jacobian { x, y, z }
+    }
+
+    /// Checks a point with `Z = 1` satisfies `y² = x³ + b'`.
+    fn is_on_curve(&self) -> bool {
+        let b = Fq2::from_be_bytes(&TWIST_B).expect("reduced constant");
+        self.y
+            .square()
+            .sub(&self.x.square().mul(&self.x))
+            .sub(&b)
+            .is_zero()
+    }
+
+    /// Applies the endomorphism ψ(x, y) = (x̄·ψₓ, ȳ·ψᵧ) to a point with `Z = 1`, which
+    /// acts on the prime order subgroup as multiplication by p.
+    fn psi(&self) -> G2Jacobian {
+        let psi_x = Fq2::from_be_bytes(&PSI_X).expect("reduced constant");
+        let psi_y = Fq2::from_be_bytes(&PSI_Y).expect("reduced constant");
+
+        G2Jacobian {
+            x: self.x.conjugate().mul(&psi_x),
+            y: self.y.conjugate().mul(&psi_y),
+            z: Fq2::ONE,
+        }
+    }
+
+    /// Multiplies a point with `Z = 1` by a 128 bit scalar given as little-endian limbs.
+    fn mul_u128(&self, scalar: &[u64; 2]) -> G2Jacobian {
+        let mut result = G2Jacobian::IDENTITY;
+        for limb in scalar.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.double();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.add_mixed(self);
+                }
+            }
+        }
+        result
+    }
+
+    /// Checks whether this point equals `rhs`, which must have `Z = 1`.
+    fn eq_affine(&self, rhs: &G2Jacobian) -> bool {
+        if self.is_identity() || rhs.is_identity() {
+            return self.is_identity() && rhs.is_identity();
+        }
+
+        let z2 = self.z.square();
+        self.x.sub(&rhs.x.mul(&z2)).is_zero() && self.y.sub(&rhs.y.mul(&z2).mul(&self.z)).is_zero()
+    }
+}
+
+/// # G2 Sum
+///
+/// Sums G2 points in the 128 byte syscall encoding with a single field inversion, returning
+/// `None` if any coordinate is not reduced modulo p or any point is not on the twist.
+pub(crate) fn g2_sum<'a>(points: impl IntoIterator<Item = &'a [u8; 128]>) -> Option<[u8; 128]> {
+    let mut sum = G2Jacobian::IDENTITY;
+    for point in points {
+        sum = sum.add_mixed(&G2Jacobian::from_bytes(point)?);
+    }
+    Some(sum.to_bytes())
+}
+
+/// # G2 MSM
+///
+/// Computes `Σ scalars[i]·points[i]` for 32 byte big-endian scalars using Straus' method, so the
+/// doublings are shared between all points and the result costs a single field inversion.
+/// Returns `None` if any coordinate is not reduced modulo p or any point is not on the twist.
+#[cfg_attr(not(target_os = "solana"), allow(dead_code))]
+pub(crate) fn g2_msm(points: &[[u8; 128]], scalars: &[[u8; 32]]) -> Option<[u8; 128]> {
+    let bases = points
+        .iter()
+        .map(G2Jacobian::from_bytes)
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut result = G2Jacobian::IDENTITY;
+    for byte in 0..32 {
+        for bit in (0..8).rev() {
+            result = result.double();
+            for (base, scalar) in bases.iter().zip(scalars) {
+                if (scalar[byte] >> bit) & 1 == 1 {
+                    result = result.add_mixed(base);
+                }
+            }
+        }
+    }
+    Some(result.to_bytes())
+}
+
+/// # G2 Validate
+///
+/// Checks a point in the 128 byte syscall encoding is a valid public key: not the identity,
+/// with coordinates reduced modulo p, on the twist and in the prime order subgroup. As G2 has a
+/// nontrivial cofactor, subgroup membership is checked as ψ(P) = [6x²]P, which costs a single
+/// 128 bit scalar multiplication.
+pub(crate) fn g2_validate(bytes: &[u8; 128]) -> Result<(), BLSError> {
+    if bytes == &[0u8; 128] {
+        return Err(BLSError::G2PointIdentityError);
+    }
+
+    let point = G2Jacobian::from_bytes_unchecked(bytes).ok_or(BLSError::G2PointNotCanonicalError)?;
+
+    if !point.is_on_curve() {
+        return Err(BLSError::G2PointNotOnCurveError);
+    }
+
+    if !point.mul_u128(&SIX_X_SQUARED).eq_affine(&point.psi()) {
+        return Err(BLSError::G2PointNotInSubgroupError);
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use ark_bn254::{G2Affine, G2Projective};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::UniformRand;
+    use num::CheckedAdd;
+
+    use super::{g2_msm, g2_sum, g2_validate, Fq, Fq2, G2Jacobian};
+    use crate::{BLSError, G2Point};
+
+    fn random_point() -> G2Affine {
+        (G2Affine::generator() * ark_bn254::Fr::rand(&mut rand::thread_rng())).into_affine()
+    }
+
+    fn to_bytes(point: &G2Affine) -> [u8; 128] {
+        G2Point::from_affine(point).unwrap().0
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        let two = Fq::ONE.double();
+        assert_eq!(two.mul(&two.inverse()).to_be_bytes(), Fq::ONE.to_be_bytes());
+        assert_eq!(Fq::ONE.neg().add(&Fq::ONE).to_be_bytes(), [0u8; 32]);
+        assert!(Fq::from_be_bytes(&crate::MODULUS_BYTES).is_none());
+
+        // u² = -1
+        let u = Fq2 {
+            c0: Fq::ZERO,
+            c1: Fq::ONE,
+        };
+        assert!(u.square().add(&Fq2::ONE).is_zero());
+
+        let x = Fq2 {
+            c0: two.add(&Fq::ONE),
+            c1: two,
+        };
+        assert!(x.mul(&x.inverse()).sub(&Fq2::ONE).is_zero());
+        assert!(x.square().sub(&x.mul(&x)).is_zero());
+    }
+
+    #[test]
+    fn matches_arkworks() {
+        let points: Vec<G2Affine> = (0..8).map(|_| random_point()).collect();
+        let bytes: Vec<[u8; 128]> = points.iter().map(to_bytes).collect();
+
+        // Sums of distinct points
+        let expected: G2Projective = points.iter().map(|p| p.into_group()).sum();
+        assert_eq!(
+            g2_sum(bytes.iter()).unwrap(),
+            to_bytes(&expected.into_affine())
+        );
+
+        // Doubling, inverses and the identity
+        let doubled = (points[0] + points[0]).into_affine();
+        assert_eq!(g2_sum([&bytes[0], &bytes[0]]).unwrap(), to_bytes(&doubled));
+        assert_eq!(
+            g2_sum([&bytes[0], &to_bytes(&-points[0])]).unwrap(),
+            [0u8; 128]
+        );
+        assert_eq!(g2_sum([&[0u8; 128], &bytes[1]]).unwrap(), bytes[1]);
+        assert_eq!(g2_sum([]).unwrap(), [0u8; 128]);
+
+        let point = G2Jacobian::from_bytes(&bytes[2]).unwrap();
+        assert_eq!(
+            point.double().add_mixed(&point).to_bytes(),
+            to_bytes(&(points[2] * ark_bn254::Fr::from(3u8)).into_affine())
+        );
+
+        // Unreduced coordinates and points off the twist are rejected
+        assert!(g2_sum([&[0xffu8; 128]]).is_none());
+        let mut off_curve = bytes[3];
+        off_curve[127] ^= 1;
+        assert!(g2_sum([&bytes[0], &off_curve]).is_none());
+        assert!(G2Point(bytes[0]).checked_add(&G2Point(off_curve)).is_none());
+    }
+
+    #[test]
+    fn msm_matches_arkworks() {
+        let points: Vec<G2Affine> = (0..4).map(|_| random_point()).collect();
+        let scalars: Vec<ark_bn254::Fr> = (0..4)
+            .map(|_| ark_bn254::Fr::rand(&mut rand::thread_rng()))
+            .collect();
+
+        let expected: G2Projective = points.iter().zip(scalars.iter()).map(|(p, s)| *p * s).sum();
+        let scalar_bytes: Vec<[u8; 32]> = scalars.iter().map(crate::threshold::scalar_to_bytes).collect();
+
+        assert_eq!(
+            g2_msm(&points.iter().map(to_bytes).collect::<Vec<_>>(), &scalar_bytes).unwrap(),
+            to_bytes(&expected.into_affine())
+        );
+
+        // Zero scalars and the identity contribute nothing
+        assert_eq!(g2_msm(&[to_bytes(&points[0])], &[[0u8; 32]]).unwrap(), [0u8; 128]);
+        assert_eq!(g2_msm(&[[0u8; 128]], &[scalar_bytes[0]]).unwrap(), [0u8; 128]);
+
+        // Points off the twist are rejected
+        let mut off_curve = to_bytes(&points[0]);
+        off_curve[127] ^= 1;
+        assert!(g2_msm(&[off_curve], &[scalar_bytes[0]]).is_none());
+    }
+
+    #[test]
+    fn validation_matches_arkworks() {
+        let point = random_point();
+        assert_eq!(g2_validate(&to_bytes(&point)), Ok(()));
+        assert_eq!(g2_validate(&[0u8; 128]), Err(BLSError::G2PointIdentityError));
+
+        // Points on the twist outside the prime order subgroup
+        let mut rng = rand::thread_rng();
+        let mut checked = 0;
+        while checked < 4 {
+            let x = ark_bn254::Fq2::rand(&mut rng);
+            let Some(point) = G2Affine::get_point_from_x_unchecked(x, false) else {
+                continue;
+            };
+            assert!(!point.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(
+                g2_validate(&to_bytes(&point)),
+                Err(BLSError::G2PointNotInSubgroupError)
+            );
+            checked += 1;
+        }
+
+        let mut off_curve = to_bytes(&point);
+        off_curve[127] ^= 1;
+        assert_eq!(g2_validate(&off_curve), Err(BLSError::G2PointNotOnCurveError));
+
+        let mut unreduced = to_bytes(&point);
+        unreduced[..32].copy_from_slice(&crate::MODULUS_BYTES);
+        assert_eq!(g2_validate(&unreduced), Err(BLSError::G2PointNotCanonicalError));
+    }
+}
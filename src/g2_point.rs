@@ -1,21 +1,202 @@
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
 pub struct G2Point(pub [u8; 128]);
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(transparent)]
 pub struct G2CompressedPoint(pub [u8; 64]);
 
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use num::CheckedAdd;
-use ark_bn254::Fr;
+use core::{
+    iter::Sum,
+    ops::{Neg, Sub},
+};
+use num::{CheckedAdd, CheckedSub};
+use zeroize::Zeroize;
+use ark_bn254::G2Affine;
 use ark_ec::AffineRepr;
+#[cfg(not(target_os = "solana"))]
+use ark_bn254::G2Projective;
+#[cfg(not(target_os = "solana"))]
+use ark_ec::{CurveGroup, VariableBaseMSM};
+#[cfg(not(target_os = "solana"))]
+use ark_ff::BigInt;
+#[cfg(not(target_os = "solana"))]
+use crate::g1_point::bigint_from_be_bytes;
+#[cfg(target_os = "solana")]
+use crate::g2_arithmetic::g2_msm;
 
 use solana_bn254::{
     compression::prelude::{alt_bn128_g2_compress, alt_bn128_g2_decompress},
     prelude::alt_bn128_pairing,
 };
 
-use crate::{BLSError, BLSSignature, HashToCurve, G2_MINUS_ONE};
+use crate::{
+    g1_point::{negate_compressed, negate_fq},
+    g2_arithmetic::{g2_sum, g2_validate},
+    schemes::Pop, BLSError, BLSSignature, BLSSignatureG2, G1Point, HashToCurve, G2_MINUS_ONE,
+};
+
+/// Runs `alt_bn128_pairing` over `input` and succeeds only if the product of pairings is one.
+pub(crate) fn pairing_check(input: &[u8]) -> Result<(), BLSError> {
+    if let Ok(r) = alt_bn128_pairing(input) {
+        if r.eq(&[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01,
+        ]) {
+            Ok(())
+        } else {
+            Err(BLSError::BLSVerificationError)
+        }
+    } else {
+        Err(BLSError::AltBN128PairingError)
+    }
+}
+
+impl BLSSignatureG2 for G2Point {
+    fn to_bytes(&self) -> Result<[u8; 128], BLSError> {
+        Ok(self.0)
+    }
+}
+
+impl BLSSignatureG2 for G2CompressedPoint {
+    fn to_bytes(&self) -> Result<[u8; 128], BLSError> {
+        Ok(G2Point::try_from(*self)?.0)
+    }
+}
 
 impl G2Point {
+    /// # Identity
+    ///
+    /// The point at infinity, encoded as all zeros.
+    pub const fn identity() -> G2Point {
+        G2Point([0u8; 128])
+    }
+
+    /// # Is Identity
+    ///
+    /// Returns whether this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 128]
+    }
+
+    /// Converts from the big-endian `(x_im, x_re, y_im, y_re)` syscall encoding into an arkworks
+    /// affine point, rejecting points that are not on the twist. Like the on-chain arithmetic,
+    /// this does not check subgroup membership, which is left to `validate`.
+    pub(crate) fn to_affine(self) -> Result<G2Affine, BLSError> {
+        if self.0 == [0u8; 128] {
+            return Ok(G2Affine::zero());
+        }
+
+        let mut bytes = self.0;
+        bytes[..64].reverse();
+        bytes[64..].reverse();
+
+        let point = G2Affine::deserialize_uncompressed_unchecked(&bytes[..])
+            .map_err(|_| BLSError::G2PointNotCanonicalError)?;
+
+        if !point.is_on_curve() {
+            return Err(BLSError::G2PointNotOnCurveError);
+        }
+
+        Ok(point)
+    }
+
+    /// Converts an arkworks affine point into the big-endian syscall encoding.
+    pub(crate) fn from_affine(point: &G2Affine) -> Result<Self, BLSError> {
+        if point.is_zero() {
+            return Ok(G2Point([0u8; 128]));
+        }
+
+        // Serialize the coordinates individually, as arkworks packs the sign of y into the
+        // uncompressed encoding of the point
+        let mut bytes = [0u8; 128];
+        point
+            .x
+            .serialize_uncompressed(&mut &mut bytes[..64])
+            .map_err(|_| BLSError::SerializationError)?;
+        point
+            .y
+            .serialize_uncompressed(&mut &mut bytes[64..])
+            .map_err(|_| BLSError::SerializationError)?;
+
+        bytes[..64].reverse();
+        bytes[64..].reverse();
+
+        Ok(G2Point(bytes))
+    }
+
+    /// # Validate
+    ///
+    /// Checks the point is a valid public key: not the identity, with coordinates reduced modulo
+    /// p, on the twist and in the prime order subgroup. Points read from untrusted input should
+    /// be validated before use, as G2 has a nontrivial cofactor.
+    pub fn validate(&self) -> Result<(), BLSError> {
+        g2_validate(&self.0)
+    }
+
+    /// # Try From Bytes
+    ///
+    /// Reads a point in the 128 byte syscall encoding, rejecting it unless it passes `validate`.
+    pub fn try_from_bytes(bytes: &[u8; 128]) -> Result<G2Point, BLSError> {
+        let point = G2Point(*bytes);
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// # Aggregate
+    ///
+    /// Sums any number of points using Jacobian coordinates, paying for a single field inversion
+    /// rather than one per addition. Prefer this over repeated `+` when aggregating many keys
+    /// on-chain.
+    pub fn aggregate(points: &[G2Point]) -> Result<G2Point, BLSError> {
+        g2_sum(points.iter().map(|point| &point.0))
+            .map(G2Point)
+            .ok_or(BLSError::AltBN128AddError)
+    }
+
+    /// # Mul Scalar
+    ///
+    /// Multiplies the point by a 32 byte big-endian scalar.
+    pub fn mul_scalar(&self, scalar: &[u8; 32]) -> Result<G2Point, BLSError> {
+        G2Point::msm(core::slice::from_ref(self), core::slice::from_ref(scalar))
+    }
+
+    /// # MSM
+    ///
+    /// Computes the multi-scalar multiplication `Σ scalars[i]·points[i]` for 32 byte big-endian
+    /// scalars. Off-chain this uses arkworks' Pippenger implementation, while on-chain it shares
+    /// doublings between all points and pays for a single field inversion.
+    pub fn msm(points: &[G2Point], scalars: &[[u8; 32]]) -> Result<G2Point, BLSError> {
+        if points.len() != scalars.len() {
+            return Err(BLSError::InputLengthError);
+        }
+
+        #[cfg(not(target_os = "solana"))]
+        {
+            let bases = points
+                .iter()
+                .copied()
+                .map(G2Point::to_affine)
+                .collect::<Result<Vec<_>, _>>()?;
+            let bigints: Vec<BigInt<4>> = scalars.iter().map(bigint_from_be_bytes).collect();
+
+            G2Point::from_affine(&G2Projective::msm_bigint(&bases, &bigints).into_affine())
+        }
+
+        #[cfg(target_os = "solana")]
+        {
+            let points: Vec<[u8; 128]> = points.iter().map(|point| point.0).collect();
+            g2_msm(&points, scalars)
+                .map(G2Point)
+                .ok_or(BLSError::AltBN128MulError)
+        }
+    }
+
     pub fn verify_signature<H: HashToCurve, T: AsRef<[u8]>, S: BLSSignature>(
         self,
         signature: S,
@@ -27,25 +208,65 @@ impl G2Point {
         input[..64].clone_from_slice(&H::try_hash_to_curve(message)?.0);
         // 2) Decompress our public key
         input[64..192].clone_from_slice(&self.0);
-        // 3) Decompress our signature
-        input[192..256].clone_from_slice(&signature.to_bytes()?);
+        // 3) Decompress our signature, rejecting the identity and points off the curve
+        let signature = G1Point(signature.to_bytes()?);
+        signature.validate()?;
+        input[192..256].clone_from_slice(&signature.0);
         // 4) Pair with -G2::one()
         input[256..].clone_from_slice(&G2_MINUS_ONE);
 
         // Calculate result
-        if let Ok(r) = alt_bn128_pairing(&input) {
-            if r.eq(&[
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x01,
-            ]) {
-                Ok(())
-            } else {
-                Err(BLSError::BLSVerificationError)
-            }
-        } else {
-            Err(BLSError::AltBN128PairingError)
+        pairing_check(&input)
+    }
+
+    /// # Aggregate Verify
+    ///
+    /// Verifies an aggregate signature over distinct messages, where `messages[i]` was signed
+    /// by the owner of `pubkeys[i]`. All pairs are checked in a single `alt_bn128_pairing` call
+    /// of n + 1 pairs. Duplicate messages are rejected, as aggregating signatures over the same
+    /// message this way is not secure without proofs of possession.
+    pub fn aggregate_verify<H: HashToCurve, S: BLSSignature>(
+        pubkeys: &[G2Point],
+        messages: &[&[u8]],
+        signature: S,
+    ) -> Result<(), BLSError> {
+        if pubkeys.is_empty() || pubkeys.len() != messages.len() {
+            return Err(BLSError::InputLengthError);
+        }
+
+        let mut sorted = messages.to_vec();
+        sorted.sort_unstable();
+        if sorted.windows(2).any(|w| w[0] == w[1]) {
+            return Err(BLSError::DuplicateMessageError);
         }
+
+        let mut input = Vec::with_capacity((pubkeys.len() + 1) * 192);
+
+        // 1) Pair each hashed message with its signer's public key
+        for (pubkey, message) in pubkeys.iter().zip(messages) {
+            input.extend_from_slice(&H::try_hash_to_curve(message)?.0);
+            input.extend_from_slice(&pubkey.0);
+        }
+        // 2) Pair the aggregate signature with -G2::one()
+        let signature = G1Point(signature.to_bytes()?);
+        signature.validate()?;
+        input.extend_from_slice(&signature.0);
+        input.extend_from_slice(&G2_MINUS_ONE);
+
+        // Calculate result
+        pairing_check(&input)
+    }
+
+    /// # Verify Possession
+    ///
+    /// Verifies a proof of possession produced by `PrivKey::prove_possession` for this
+    /// public key. Only keys that pass this check should ever be aggregated.
+    pub fn verify_possession<H: HashToCurve, S: BLSSignature>(
+        self,
+        proof: S,
+    ) -> Result<(), BLSError> {
+        let pubkey = G2CompressedPoint::try_from(&self)?;
+        self.verify_signature::<Pop<H>, &[u8], S>(proof, &pubkey.0)
     }
 }
 
@@ -60,27 +281,91 @@ impl core::ops::Add for G2Point {
 
 impl CheckedAdd for G2Point {
     fn checked_add(&self, rhs: &Self) -> Option<Self> {
-        let result = (|| -> Result<Self, BLSError> {
-            let mut s0 = G2CompressedPoint::try_from(self)?.0;
-            let mut s1 = G2CompressedPoint::try_from(rhs)?.0;
+        g2_sum([&self.0, &rhs.0]).map(G2Point)
+    }
+}
+
+/// Sums points in Jacobian coordinates like `G2Point::aggregate`, starting from the identity.
+impl Sum for G2Point {
+    fn sum<I: Iterator<Item = G2Point>>(iter: I) -> G2Point {
+        let points: Vec<[u8; 128]> = iter.map(|point| point.0).collect();
+        g2_sum(points.iter())
+            .map(G2Point)
+            .expect("G2Point addition failed")
+    }
+}
+
+impl<'a> Sum<&'a G2Point> for G2Point {
+    fn sum<I: Iterator<Item = &'a G2Point>>(iter: I) -> G2Point {
+        g2_sum(iter.map(|point| &point.0))
+            .map(G2Point)
+            .expect("G2Point addition failed")
+    }
+}
+
+impl Sum for G2CompressedPoint {
+    fn sum<I: Iterator<Item = G2CompressedPoint>>(iter: I) -> G2CompressedPoint {
+        let sum: G2Point = iter
+            .map(|point| G2Point::try_from(point).expect("G2Point decompression failed"))
+            .sum();
+        G2CompressedPoint::try_from(&sum).expect("G2Point compression failed")
+    }
+}
+
+impl<'a> Sum<&'a G2CompressedPoint> for G2CompressedPoint {
+    fn sum<I: Iterator<Item = &'a G2CompressedPoint>>(iter: I) -> G2CompressedPoint {
+        iter.copied().sum()
+    }
+}
+
+impl Neg for G2Point {
+    type Output = G2Point;
+
+    fn neg(self) -> G2Point {
+        let mut result = self.0;
+        result[64..96].clone_from_slice(&negate_fq(&self.0[64..96]));
+        result[96..].clone_from_slice(&negate_fq(&self.0[96..]));
+        G2Point(result)
+    }
+}
 
-            s0.reverse();
-            s1.reverse();
+impl Sub for G2Point {
+    type Output = G2Point;
 
-            let g2_agg = ark_bn254::G2Affine::deserialize_compressed(&s0[..])
-                .map_err(|_| BLSError::G2PointCompressionError)?
-                + ark_bn254::G2Affine::deserialize_compressed(&s1[..])
-                    .map_err(|_| BLSError::G2PointCompressionError)?;
+    fn sub(self, rhs: Self) -> G2Point {
+        self.checked_sub(&rhs).expect("G2Point subtraction failed")
+    }
+}
+
+impl CheckedSub for G2Point {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(&-*rhs)
+    }
+}
+
+impl Neg for G2CompressedPoint {
+    type Output = G2CompressedPoint;
+
+    fn neg(self) -> G2CompressedPoint {
+        G2CompressedPoint(negate_compressed(&self.0))
+    }
+}
 
-            let mut g2_agg_bytes = [0u8; 64];
-            g2_agg
-                .serialize_compressed(&mut &mut g2_agg_bytes[..])
-                .map_err(|_| BLSError::SerializationError)?;
+impl Sub for G2CompressedPoint {
+    type Output = G2CompressedPoint;
 
-            g2_agg_bytes.reverse();
+    fn sub(self, rhs: Self) -> G2CompressedPoint {
+        self.checked_sub(&rhs).expect("G2CompressedPoint subtraction failed")
+    }
+}
 
-            G2Point::try_from(G2CompressedPoint(g2_agg_bytes))
-                .map_err(|_| BLSError::G2PointDecompressionError)
+impl CheckedSub for G2CompressedPoint {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let result = (|| -> Result<Self, BLSError> {
+            let difference = G2Point::try_from(*self)?
+                .checked_sub(&G2Point::try_from(*rhs)?)
+                .ok_or(BLSError::G2PointCompressionError)?;
+            G2CompressedPoint::try_from(&difference)
         })();
 
         result.ok()
@@ -88,6 +373,46 @@ impl CheckedAdd for G2Point {
 }
 
 impl G2CompressedPoint {
+    /// # Identity
+    ///
+    /// The point at infinity, encoded as all zeros.
+    pub const fn identity() -> G2CompressedPoint {
+        G2CompressedPoint([0u8; 64])
+    }
+
+    /// # Is Identity
+    ///
+    /// Returns whether this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 64]
+    }
+
+    /// # Validate
+    ///
+    /// Decompresses the point and checks it passes `G2Point::validate`.
+    pub fn validate(&self) -> Result<(), BLSError> {
+        if self.0 == [0u8; 64] {
+            return Err(BLSError::G2PointIdentityError);
+        }
+        G2Point::try_from(*self)?.validate()
+    }
+
+    /// # Try From Bytes
+    ///
+    /// Reads a compressed point, rejecting it unless it passes `validate`.
+    pub fn try_from_bytes(bytes: &[u8; 64]) -> Result<G2CompressedPoint, BLSError> {
+        let point = G2CompressedPoint(*bytes);
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// # Mul Scalar
+    ///
+    /// Multiplies the compressed point by a 32 byte big-endian scalar.
+    pub fn mul_scalar(&self, scalar: &[u8; 32]) -> Result<G2CompressedPoint, BLSError> {
+        G2CompressedPoint::try_from(&G2Point::try_from(*self)?.mul_scalar(scalar)?)
+    }
+
     pub fn verify_signature<H: HashToCurve, T: AsRef<[u8]>, S: BLSSignature>(
         self,
         signature: S,
@@ -99,25 +424,27 @@ impl G2CompressedPoint {
         input[..64].clone_from_slice(&H::try_hash_to_curve(message)?.0);
         // 2) Decompress our public key
         input[64..192].clone_from_slice(&G2Point::try_from(self)?.0);
-        // 3) Decompress our signature
-        input[192..256].clone_from_slice(&signature.to_bytes()?);
+        // 3) Decompress our signature, rejecting the identity and points off the curve
+        let signature = G1Point(signature.to_bytes()?);
+        signature.validate()?;
+        input[192..256].clone_from_slice(&signature.0);
         // 4) Pair with -G2::one()
         input[256..].clone_from_slice(&G2_MINUS_ONE);
 
         // Calculate result
-        if let Ok(r) = alt_bn128_pairing(&input) {
-            if r.eq(&[
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x01,
-            ]) {
-                Ok(())
-            } else {
-                Err(BLSError::BLSVerificationError)
-            }
-        } else {
-            Err(BLSError::AltBN128PairingError)
-        }
+        pairing_check(&input)
+    }
+
+    /// # Verify Possession
+    ///
+    /// Verifies a proof of possession produced by `PrivKey::prove_possession` for this
+    /// compressed public key.
+    pub fn verify_possession<H: HashToCurve, S: BLSSignature>(
+        self,
+        proof: S,
+    ) -> Result<(), BLSError> {
+        let message = self.0;
+        self.verify_signature::<Pop<H>, &[u8], S>(proof, &message)
     }
 }
 
@@ -126,15 +453,12 @@ impl TryFrom<&crate::PrivKey> for G2CompressedPoint {
     type Error = BLSError;
 
     fn try_from(value: &crate::PrivKey) -> Result<G2CompressedPoint, Self::Error> {
-        let mut pk = value.0;
-
-        pk.reverse();
-
-        let secret_key =
-            Fr::deserialize_compressed(&pk[..]).map_err(|_| BLSError::SecretKeyError)?;
+        let mut secret_key = value.to_scalar()?;
 
         let g2_public_key = ark_bn254::G2Affine::generator() * secret_key;
 
+        secret_key.zeroize();
+
         let mut g2_public_key_bytes = [0u8; 64];
 
         g2_public_key
@@ -158,6 +482,19 @@ impl TryFrom<&crate::PrivKey> for G2Point {
     }
 }
 
+/// A point equals its compressed form if compressing it yields the same bytes.
+impl PartialEq<G2CompressedPoint> for G2Point {
+    fn eq(&self, other: &G2CompressedPoint) -> bool {
+        alt_bn128_g2_compress(&self.0).is_ok_and(|compressed| compressed == other.0)
+    }
+}
+
+impl PartialEq<G2Point> for G2CompressedPoint {
+    fn eq(&self, other: &G2Point) -> bool {
+        other == self
+    }
+}
+
 impl TryFrom<&G2Point> for G2CompressedPoint {
     type Error = BLSError;
 
@@ -0,0 +1,240 @@
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use crate::{errors::BLSError, privkey::PrivKey};
+
+/// Salt used by `hkdf_mod_r`. EIP-2333 uses `BLS-SIG-KEYGEN-SALT-`, so we suffix it with the
+/// curve to keep BN254 keys independent of the BLS12-381 keys derived from the same seed.
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-BN254-";
+
+/// Number of bytes of HKDF output reduced modulo r, L = ceil((3 * ceil(log2(r))) / 16). This is
+/// 48 for both BN254 and BLS12-381.
+const L: usize = 48;
+
+/// Number of 32 byte chunks in each half of a Lamport secret key.
+const LAMPORT_CHUNKS: usize = 255;
+
+impl PrivKey {
+    /// # From Seed
+    ///
+    /// Derives the master private key from a seed of at least 32 bytes, following EIP-2333 with
+    /// a BN254 specific salt and the output reduced modulo the BN254 scalar order. A seed shared
+    /// with an Ethereum validator therefore never yields the same key material.
+    pub fn from_seed(seed: &[u8]) -> Result<PrivKey, BLSError> {
+        if seed.len() < 32 {
+            return Err(BLSError::KeyDerivationError);
+        }
+
+        into_privkey(hkdf_mod_r::<Fr>(seed, b"", KEYGEN_SALT))
+    }
+
+    /// # Derive Child
+    ///
+    /// Derives the hardened child private key at `index`, following EIP-2333's Lamport based
+    /// `derive_child_SK`.
+    pub fn derive_child(&self, index: u32) -> Result<PrivKey, BLSError> {
+        into_privkey(derive_child_sk::<Fr>(self.as_bytes(), index, KEYGEN_SALT))
+    }
+
+    /// # From Seed And Path
+    ///
+    /// Derives a private key from a seed and an EIP-2334 style path. EIP-2334's `12381` purpose
+    /// belongs to BLS12-381, so BN254 keys should use their own, such as `m/254/501/0/0` for
+    /// purpose `254` and Solana's coin type `501`.
+    pub fn from_seed_and_path(seed: &[u8], path: &str) -> Result<PrivKey, BLSError> {
+        let mut segments = path.split('/');
+
+        if segments.next() != Some("m") {
+            return Err(BLSError::KeyDerivationError);
+        }
+
+        segments.try_fold(PrivKey::from_seed(seed)?, |key, segment| {
+            let index = segment
+                .parse::<u32>()
+                .map_err(|_| BLSError::KeyDerivationError)?;
+            key.derive_child(index)
+        })
+    }
+}
+
+/// Wraps derived key bytes into a `PrivKey`, zeroizing the intermediate copy.
+fn into_privkey(mut bytes: [u8; 32]) -> Result<PrivKey, BLSError> {
+    let privkey = PrivKey::try_from_bytes(&bytes);
+    bytes.zeroize();
+    privkey
+}
+
+/// Implements `HKDF_mod_r` from EIP-2333 for an arbitrary scalar field and salt, returning the
+/// key as 32 big-endian bytes. All intermediate key material is zeroized.
+fn hkdf_mod_r<F: PrimeField>(ikm: &[u8], key_info: &[u8], keygen_salt: &[u8]) -> [u8; 32] {
+    let mut salt = Sha256::digest(keygen_salt);
+    let mut ikm = [ikm, &[0u8]].concat();
+
+    loop {
+        let mut okm = [0u8; L];
+        Hkdf::<Sha256>::new(Some(&salt), &ikm)
+            .expand(&[key_info, &(L as u16).to_be_bytes()].concat(), &mut okm)
+            .expect("48 bytes is a valid HKDF-SHA256 output length");
+
+        let mut sk = F::from_be_bytes_mod_order(&okm);
+        okm.zeroize();
+
+        if !sk.is_zero() {
+            let mut sk_bytes = sk.into_bigint().to_bytes_be();
+            let mut bytes = [0u8; 32];
+            bytes[32 - sk_bytes.len()..].clone_from_slice(&sk_bytes);
+
+            sk_bytes.zeroize();
+            sk.zeroize();
+            ikm.zeroize();
+
+            return bytes;
+        }
+
+        salt = Sha256::digest(salt);
+    }
+}
+
+/// Implements `derive_child_SK` from EIP-2333.
+fn derive_child_sk<F: PrimeField>(parent: &[u8; 32], index: u32, keygen_salt: &[u8]) -> [u8; 32] {
+    let mut lamport_pk = parent_sk_to_lamport_pk(parent, index);
+    let child = hkdf_mod_r::<F>(&lamport_pk, b"", keygen_salt);
+    lamport_pk.zeroize();
+    child
+}
+
+/// Implements `parent_SK_to_lamport_PK` from EIP-2333.
+fn parent_sk_to_lamport_pk(parent: &[u8; 32], index: u32) -> [u8; 32] {
+    let salt = index.to_be_bytes();
+    let mut not_ikm = parent.map(|b| !b);
+
+    let mut hasher = Sha256::new();
+
+    for ikm in [&parent[..], &not_ikm[..]] {
+        let mut lamport_sk = ikm_to_lamport_sk(ikm, &salt);
+        lamport_sk
+            .chunks_exact(32)
+            .for_each(|chunk| hasher.update(Sha256::digest(chunk)));
+        lamport_sk.zeroize();
+    }
+
+    not_ikm.zeroize();
+
+    hasher.finalize().into()
+}
+
+/// Implements `IKM_to_lamport_SK` from EIP-2333, returning all 255 chunks concatenated.
+fn ikm_to_lamport_sk(ikm: &[u8], salt: &[u8]) -> Vec<u8> {
+    let mut okm = vec![0u8; 32 * LAMPORT_CHUNKS];
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(&[], &mut okm)
+        .expect("8160 bytes is a valid HKDF-SHA256 output length");
+    okm
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use dashu::integer::UBig;
+
+    use super::{derive_child_sk, hkdf_mod_r, KEYGEN_SALT};
+    use crate::{BLSError, G1Point, G2Point, PrivKey, Sha256Normalized};
+
+    // We check our implementation against EIP-2333's vectors using the BLS12-381 scalar field
+    // and the salt EIP-2333 specifies
+    type Bls12381Fr = ark_bls12_381::Fr;
+    const EIP2333_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+    fn to_bytes(num: &str) -> [u8; 32] {
+        let num_bytes = num.parse::<UBig>().unwrap().to_be_bytes();
+        let mut bytes = [0u8; 32];
+        bytes[32 - num_bytes.len()..].clone_from_slice(&num_bytes);
+        bytes
+    }
+
+    #[test]
+    fn eip2333_test_case_0() {
+        let seed = [
+            0xc5, 0x52, 0x57, 0xc3, 0x60, 0xc0, 0x7c, 0x72, 0x02, 0x9a, 0xeb, 0xc1, 0xb5, 0x3c, 0x05, 0xed, 0x03, 0x62, 0xad, 0xa3, 0x8e, 0xad, 0x3e, 0x3e, 0x9e, 0xfa, 0x37, 0x08, 0xe5, 0x34, 0x95, 0x53, 0x1f, 0x09, 0xa6, 0x98, 0x75, 0x99, 0xd1, 0x82, 0x64, 0xc1, 0xe1, 0xc9, 0x2f, 0x2c, 0xf1, 0x41, 0x63, 0x0c, 0x7a, 0x3c, 0x4a, 0xb7, 0xc8, 0x1b, 0x2f, 0x00, 0x16, 0x98, 0xe7, 0x46, 0x3b, 0x04,
+        ];
+
+        let master = hkdf_mod_r::<Bls12381Fr>(&seed, b"", EIP2333_SALT);
+        assert_eq!(
+            to_bytes("6083874454709270928345386274498605044986640685124978867557563392430687146096"),
+            master
+        );
+        assert_eq!(
+            to_bytes("20397789859736650942317412262472558107875392172444076792671091975210932703118"),
+            derive_child_sk::<Bls12381Fr>(&master, 0, EIP2333_SALT)
+        );
+    }
+
+    #[test]
+    fn eip2333_test_case_1() {
+        let seed = [
+            0x31, 0x41, 0x59, 0x26, 0x53, 0x58, 0x97, 0x93, 0x23, 0x84, 0x62, 0x64, 0x33, 0x83, 0x27, 0x95, 0x02, 0x88, 0x41, 0x97, 0x16, 0x93, 0x99, 0x37, 0x51, 0x05, 0x82, 0x09, 0x74, 0x94, 0x45, 0x92,
+        ];
+
+        let master = hkdf_mod_r::<Bls12381Fr>(&seed, b"", EIP2333_SALT);
+        assert_eq!(
+            to_bytes("29757020647961307431480504535336562678282505419141012933316116377660817309383"),
+            master
+        );
+        assert_eq!(
+            to_bytes("25457201688850691947727629385191704516744796114925897962676248250929345014287"),
+            derive_child_sk::<Bls12381Fr>(&master, 3141592653, EIP2333_SALT)
+        );
+    }
+
+    #[test]
+    fn derived_keys_are_valid() {
+        let seed = [0x42u8; 32];
+
+        let key = PrivKey::from_seed_and_path(&seed, "m/254/501/0/0").unwrap();
+        assert!(PrivKey::try_from_bytes(key.as_bytes()).is_ok());
+
+        // Paths are equivalent to chained child derivation
+        let chained = PrivKey::from_seed(&seed)
+            .and_then(|k| k.derive_child(254))
+            .and_then(|k| k.derive_child(501))
+            .and_then(|k| k.derive_child(0))
+            .and_then(|k| k.derive_child(0))
+            .unwrap();
+        assert_eq!(chained, key);
+
+        let signature = key.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+        let pubkey = G2Point::try_from(&key).expect("Invalid private key");
+        assert!(pubkey
+            .verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+    }
+
+    #[test]
+    fn keygen_salt_is_bn254_specific() {
+        let seed = [0x42u8; 32];
+
+        // The same seed must not yield the key material of the generic EIP-2333 derivation
+        let master = PrivKey::from_seed(&seed).unwrap();
+        assert_eq!(*master.as_bytes(), hkdf_mod_r::<ark_bn254::Fr>(&seed, b"", KEYGEN_SALT));
+        assert_ne!(*master.as_bytes(), hkdf_mod_r::<ark_bn254::Fr>(&seed, b"", EIP2333_SALT));
+        assert_ne!(
+            *master.derive_child(0).unwrap().as_bytes(),
+            derive_child_sk::<ark_bn254::Fr>(master.as_bytes(), 0, EIP2333_SALT)
+        );
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        assert_eq!(PrivKey::from_seed(&[0u8; 31]).err(), Some(BLSError::KeyDerivationError));
+        assert_eq!(
+            PrivKey::from_seed_and_path(&[0u8; 32], "254/501").err(),
+            Some(BLSError::KeyDerivationError)
+        );
+        assert_eq!(
+            PrivKey::from_seed_and_path(&[0u8; 32], "m/254/x").err(),
+            Some(BLSError::KeyDerivationError)
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use rand::RngCore;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{errors::BLSError, g2_point::G2CompressedPoint, privkey::PrivKey};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// Upper bound on the memory scrypt may use, `128·n·r` bytes, which is 256 MiB for the EIP-2335
+/// default of n = 2^18 and r = 8. Without it an untrusted keystore could make us abort on an
+/// enormous allocation before the checksum is checked.
+const MAX_SCRYPT_MEMORY: u128 = 256 << 20;
+
+/// Upper bound on scrypt's work, `n·r·p`, at 8 times the EIP-2335 default.
+const MAX_SCRYPT_WORK: u128 = 1 << 24;
+
+/// Upper bound on PBKDF2 iterations, at 64 times the EIP-2335 default.
+const MAX_PBKDF2_C: u64 = 1 << 24;
+
+/// # Keystore KDF
+///
+/// Key derivation function used to turn a password into the keystore decryption key.
+pub enum KeystoreKdf {
+    /// scrypt with cost parameter `n` (a power of two), block size `r` and parallelism `p`
+    Scrypt { n: u32, r: u32, p: u32 },
+    /// PBKDF2 with HMAC-SHA256 and `c` iterations
+    Pbkdf2 { c: u32 },
+}
+
+impl Default for KeystoreKdf {
+    /// The parameters recommended by EIP-2335
+    fn default() -> Self {
+        KeystoreKdf::Scrypt {
+            n: 262144,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+impl PrivKey {
+    /// # To Keystore
+    ///
+    /// Encrypts this private key into an EIP-2335 style JSON keystore using scrypt and
+    /// AES-128-CTR. The `pubkey` field holds our hex encoded `G2CompressedPoint`.
+    pub fn to_keystore(&self, password: &str) -> Result<String, BLSError> {
+        self.to_keystore_with_kdf(password, KeystoreKdf::default(), "")
+    }
+
+    /// # To Keystore With KDF
+    ///
+    /// Same as `to_keystore`, with a custom KDF and an optional EIP-2334 derivation `path`.
+    pub fn to_keystore_with_kdf(
+        &self,
+        password: &str,
+        kdf: KeystoreKdf,
+        path: &str,
+    ) -> Result<String, BLSError> {
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        let mut uuid = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut iv);
+        rand::thread_rng().fill_bytes(&mut uuid);
+
+        let kdf = match kdf {
+            KeystoreKdf::Scrypt { n, r, p } => json!({
+                "function": "scrypt",
+                "params": { "dklen": 32, "n": n, "r": r, "p": p, "salt": hex::encode(salt) },
+                "message": "",
+            }),
+            KeystoreKdf::Pbkdf2 { c } => json!({
+                "function": "pbkdf2",
+                "params": { "dklen": 32, "c": c, "prf": "hmac-sha256", "salt": hex::encode(salt) },
+                "message": "",
+            }),
+        };
+
+        let decryption_key = Zeroizing::new(derive_decryption_key(&kdf, password)?);
+
+        let mut cipher_message = *self.as_bytes();
+        Aes128Ctr::new((&decryption_key[..16]).into(), (&iv).into())
+            .apply_keystream(&mut cipher_message);
+
+        let checksum = Sha256::digest([&decryption_key[16..], &cipher_message[..]].concat());
+
+        let pubkey = G2CompressedPoint::try_from(self)?;
+
+        let keystore = json!({
+            "crypto": {
+                "kdf": kdf,
+                "checksum": {
+                    "function": "sha256",
+                    "params": {},
+                    "message": hex::encode(checksum),
+                },
+                "cipher": {
+                    "function": "aes-128-ctr",
+                    "params": { "iv": hex::encode(iv) },
+                    "message": hex::encode(cipher_message),
+                },
+            },
+            "description": "",
+            "pubkey": hex::encode(pubkey.0),
+            "path": path,
+            "uuid": format_uuid(uuid),
+            "version": 4,
+        });
+
+        serde_json::to_string_pretty(&keystore).map_err(|_| BLSError::SerializationError)
+    }
+
+    /// # From Keystore
+    ///
+    /// Decrypts an EIP-2335 style JSON keystore. Returns `KeystoreChecksumError` if the password
+    /// is wrong, and checks the decrypted key against the `pubkey` field when it is present.
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<PrivKey, BLSError> {
+        let keystore: Value =
+            serde_json::from_str(keystore).map_err(|_| BLSError::KeystoreError)?;
+
+        if keystore["version"].as_u64() != Some(4) {
+            return Err(BLSError::KeystoreError);
+        }
+
+        let crypto = &keystore["crypto"];
+
+        if crypto["checksum"]["function"].as_str() != Some("sha256")
+            || crypto["cipher"]["function"].as_str() != Some("aes-128-ctr")
+        {
+            return Err(BLSError::KeystoreError);
+        }
+
+        let decryption_key = Zeroizing::new(derive_decryption_key(&crypto["kdf"], password)?);
+
+        let cipher_message = decode_hex(&crypto["cipher"]["message"])?;
+        let iv: [u8; 16] = decode_hex(&crypto["cipher"]["params"]["iv"])?
+            .try_into()
+            .map_err(|_| BLSError::KeystoreError)?;
+
+        let checksum = Sha256::digest([&decryption_key[16..], &cipher_message[..]].concat());
+        if !bool::from(checksum[..].ct_eq(&decode_hex(&crypto["checksum"]["message"])?)) {
+            return Err(BLSError::KeystoreChecksumError);
+        }
+
+        let mut secret: [u8; 32] = cipher_message
+            .try_into()
+            .map_err(|_| BLSError::KeystoreError)?;
+        Aes128Ctr::new((&decryption_key[..16]).into(), (&iv).into()).apply_keystream(&mut secret);
+
+        let privkey = PrivKey::try_from_bytes(&secret);
+        secret.zeroize();
+        let privkey = privkey?;
+
+        let pubkey = decode_hex(&keystore["pubkey"])?;
+        if !pubkey.is_empty() && pubkey[..] != G2CompressedPoint::try_from(&privkey)?.0[..] {
+            return Err(BLSError::KeystoreError);
+        }
+
+        Ok(privkey)
+    }
+}
+
+/// Derives the 32 byte decryption key from the password using the keystore's KDF module.
+fn derive_decryption_key(kdf: &Value, password: &str) -> Result<[u8; 32], BLSError> {
+    let params = &kdf["params"];
+    let salt = decode_hex(&params["salt"])?;
+
+    if params["dklen"].as_u64() != Some(32) {
+        return Err(BLSError::KeystoreError);
+    }
+
+    let password = Zeroizing::new(process_password(password));
+    let mut decryption_key = [0u8; 32];
+
+    match kdf["function"].as_str() {
+        Some("scrypt") => {
+            let n = params["n"].as_u64().ok_or(BLSError::KeystoreError)?;
+            let r = params["r"].as_u64().ok_or(BLSError::KeystoreError)?;
+            let p = params["p"].as_u64().ok_or(BLSError::KeystoreError)?;
+
+            // n·r always fits in a u128, but scaling it further may not
+            let nr = n as u128 * r as u128;
+            if !n.is_power_of_two()
+                || n < 2
+                || nr.checked_mul(128).is_none_or(|memory| memory > MAX_SCRYPT_MEMORY)
+                || nr.checked_mul(p as u128).is_none_or(|work| work > MAX_SCRYPT_WORK)
+            {
+                return Err(BLSError::KeystoreError);
+            }
+
+            let params = scrypt::Params::new(
+                n.trailing_zeros() as u8,
+                r.try_into().map_err(|_| BLSError::KeystoreError)?,
+                p.try_into().map_err(|_| BLSError::KeystoreError)?,
+                32,
+            )
+            .map_err(|_| BLSError::KeystoreError)?;
+
+            scrypt::scrypt(password.as_bytes(), &salt, &params, &mut decryption_key)
+                .map_err(|_| BLSError::KeystoreError)?;
+        }
+        Some("pbkdf2") => {
+            let c = params["c"].as_u64().ok_or(BLSError::KeystoreError)?;
+
+            if params["prf"].as_str() != Some("hmac-sha256") || c > MAX_PBKDF2_C {
+                return Err(BLSError::KeystoreError);
+            }
+
+            pbkdf2::pbkdf2_hmac::<Sha256>(
+                password.as_bytes(),
+                &salt,
+                c.try_into().map_err(|_| BLSError::KeystoreError)?,
+                &mut decryption_key,
+            );
+        }
+        _ => return Err(BLSError::KeystoreError),
+    }
+
+    Ok(decryption_key)
+}
+
+/// Normalizes the password to NFKD and strips C0, C1 and Delete control codes, as per EIP-2335.
+fn process_password(password: &str) -> String {
+    password
+        .nfkd()
+        .filter(|c| !matches!(*c as u32, 0x00..=0x1f | 0x7f..=0x9f))
+        .collect()
+}
+
+fn decode_hex(value: &Value) -> Result<Vec<u8>, BLSError> {
+    hex::decode(value.as_str().ok_or(BLSError::KeystoreError)?).map_err(|_| BLSError::KeystoreError)
+}
+
+/// Formats 16 random bytes as a version 4 UUID.
+fn format_uuid(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::{KeystoreKdf, MAX_PBKDF2_C};
+    use crate::{BLSError, PrivKey};
+
+    /// EIP-2335 pbkdf2 test vector, with the BLS12-381 pubkey removed
+    const EIP2335_PBKDF2: &str = r#"{
+        "crypto": {
+            "kdf": {
+                "function": "pbkdf2",
+                "params": {
+                    "dklen": 32,
+                    "c": 262144,
+                    "prf": "hmac-sha256",
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                },
+                "message": ""
+            },
+            "checksum": {
+                "function": "sha256",
+                "params": {},
+                "message": "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1"
+            },
+            "cipher": {
+                "function": "aes-128-ctr",
+                "params": {
+                    "iv": "264daa3f303d7259501c93d997d84fe6"
+                },
+                "message": "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad"
+            }
+        },
+        "description": "This is a test keystore that uses PBKDF2 to secure the secret.",
+        "pubkey": "",
+        "path": "m/12381/60/0/0",
+        "uuid": "64625def-3331-4eea-ab6f-782f3ed16a83",
+        "version": 4
+    }"#;
+
+    /// EIP-2335 scrypt test vector, with the BLS12-381 pubkey removed
+    const EIP2335_SCRYPT: &str = r#"{
+        "crypto": {
+            "kdf": {
+                "function": "scrypt",
+                "params": {
+                    "dklen": 32,
+                    "n": 262144,
+                    "p": 1,
+                    "r": 8,
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                },
+                "message": ""
+            },
+            "checksum": {
+                "function": "sha256",
+                "params": {},
+                "message": "d2217fe5f3e9a1e34581ef8a78f7c9928e436d36dacc5e846690a5581e8ea484"
+            },
+            "cipher": {
+                "function": "aes-128-ctr",
+                "params": {
+                    "iv": "264daa3f303d7259501c93d997d84fe6"
+                },
+                "message": "06ae90d55fe0a6e9c5c3bc5b170827b2e5cce3929ed3f116c2811e6366dfe20f"
+            }
+        },
+        "description": "This is a test keystore that uses scrypt to secure the secret.",
+        "pubkey": "",
+        "path": "m/12381/60/3141592653/0",
+        "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+        "version": 4
+    }"#;
+
+    const PASSWORD: &str = "\u{1d531}\u{1d522}\u{1d530}\u{1d531}\u{1d52d}\u{1d51e}\u{1d530}\u{1d530}\u{1d534}\u{1d52c}\u{1d52f}\u{1d521}\u{1f511}";
+
+    const SECRET: [u8; 32] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x19, 0xd6, 0x68, 0x9c, 0x08, 0x5a, 0xe1, 0x65, 0x83, 0x1e, 0x93, 0x4f, 0xf7, 0x63, 0xae, 0x46, 0xa2, 0xa6, 0xc1, 0x72, 0xb3, 0xf1, 0xb6, 0x0a, 0x8c, 0xe2, 0x6f,
+    ];
+
+    #[test]
+    fn decrypt_eip2335_pbkdf2_vector() {
+        let privkey = PrivKey::from_keystore(EIP2335_PBKDF2, PASSWORD).unwrap();
+        assert_eq!(&SECRET, privkey.as_bytes());
+    }
+
+    #[test]
+    fn decrypt_eip2335_scrypt_vector() {
+        let privkey = PrivKey::from_keystore(EIP2335_SCRYPT, PASSWORD).unwrap();
+        assert_eq!(&SECRET, privkey.as_bytes());
+    }
+
+    #[test]
+    fn round_trip_scrypt() {
+        let privkey = PrivKey::from_random();
+        let keystore = privkey
+            .to_keystore_with_kdf("password", KeystoreKdf::Scrypt { n: 1024, r: 8, p: 1 }, "")
+            .unwrap();
+
+        assert_eq!(PrivKey::from_keystore(&keystore, "password").unwrap(), privkey);
+        assert_eq!(
+            PrivKey::from_keystore(&keystore, "wrong password").err(),
+            Some(BLSError::KeystoreChecksumError)
+        );
+    }
+
+    #[test]
+    fn round_trip_pbkdf2() {
+        let privkey = PrivKey::from_random();
+        let keystore = privkey
+            .to_keystore_with_kdf("password", KeystoreKdf::Pbkdf2 { c: 1024 }, "m/254/501/0/0")
+            .unwrap();
+
+        assert_eq!(PrivKey::from_keystore(&keystore, "password").unwrap(), privkey);
+    }
+
+    #[test]
+    fn rejects_mismatched_pubkey() {
+        let privkey = PrivKey::from_random();
+        let other = PrivKey::from_random();
+
+        let keystore = privkey
+            .to_keystore_with_kdf("password", KeystoreKdf::Pbkdf2 { c: 1024 }, "")
+            .unwrap();
+        let other_keystore = other
+            .to_keystore_with_kdf("password", KeystoreKdf::Pbkdf2 { c: 1024 }, "")
+            .unwrap();
+
+        let mut keystore: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+        let other_keystore: serde_json::Value = serde_json::from_str(&other_keystore).unwrap();
+        keystore["pubkey"] = other_keystore["pubkey"].clone();
+
+        assert_eq!(
+            PrivKey::from_keystore(&keystore.to_string(), "password").err(),
+            Some(BLSError::KeystoreError)
+        );
+    }
+
+    #[test]
+    fn rejects_excessive_kdf_params() {
+        let keystore = PrivKey::from_random()
+            .to_keystore_with_kdf("password", KeystoreKdf::Pbkdf2 { c: 1024 }, "")
+            .unwrap();
+        let mut keystore: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+        keystore["crypto"]["kdf"]["params"]["c"] = (MAX_PBKDF2_C + 1).into();
+        assert_eq!(
+            PrivKey::from_keystore(&keystore.to_string(), "password").err(),
+            Some(BLSError::KeystoreError)
+        );
+
+        let keystore = PrivKey::from_random()
+            .to_keystore_with_kdf("password", KeystoreKdf::Scrypt { n: 1024, r: 8, p: 1 }, "")
+            .unwrap();
+        let mut keystore: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+        // Too much memory, from either n or r, and too much work through p
+        for (n, r, p) in [
+            (1u64 << 19, 8u64, 1u64),
+            (1024, 1 << 29, 1),
+            (1024, 8, 1 << 12),
+            (1 << 63, u64::MAX, u64::MAX),
+        ] {
+            let params = &mut keystore["crypto"]["kdf"]["params"];
+            params["n"] = n.into();
+            params["r"] = r.into();
+            params["p"] = p.into();
+            assert_eq!(
+                PrivKey::from_keystore(&keystore.to_string(), "password").err(),
+                Some(BLSError::KeystoreError)
+            );
+        }
+
+        // The limits reject parameters before deriving, so the same call fails fast
+        assert_eq!(
+            PrivKey::from_random()
+                .to_keystore_with_kdf("password", KeystoreKdf::Pbkdf2 { c: u32::MAX }, "")
+                .err(),
+            Some(BLSError::KeystoreError)
+        );
+    }
+}
@@ -7,12 +7,45 @@ pub use schemes::*;
 pub mod privkey;
 pub use privkey::*;
 
+pub mod key_derivation;
+
+#[cfg(not(target_os = "solana"))]
+pub mod keystore;
+#[cfg(not(target_os = "solana"))]
+pub use keystore::*;
+
 pub mod g1_point;
 pub use g1_point::*;
 
 pub mod g2_point;
 pub use g2_point::*;
 
+mod g2_arithmetic;
+
+pub mod threshold;
+pub use threshold::*;
+
+#[cfg(not(target_os = "solana"))]
+pub mod dkg;
+#[cfg(not(target_os = "solana"))]
+pub use dkg::*;
+
+pub mod weighted_aggregate;
+pub use weighted_aggregate::*;
+
+#[cfg(not(target_os = "solana"))]
+pub mod batch;
+#[cfg(not(target_os = "solana"))]
+pub use batch::*;
+
+mod display;
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test_utils;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
 pub mod errors;
 pub use errors::*;
 
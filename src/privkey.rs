@@ -2,25 +2,104 @@
 #[cfg(not(target_os = "solana"))]
 use rand::RngCore;
 
+use ark_bn254::Fr;
+use ark_serialize::CanonicalDeserialize;
 use solana_bn254::prelude::alt_bn128_multiplication;
+use subtle::{Choice, ConstantTimeEq, ConstantTimeLess};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{errors::BLSError, g1_point::G1Point, schemes::HashToCurve};
+use crate::{
+    errors::BLSError,
+    g1_point::G1Point,
+    g2_point::{G2CompressedPoint, G2Point},
+    schemes::{HashToCurve, HashToCurveG2, Pop},
+};
 
-pub struct PrivKey(pub [u8; 32]);
+/// # PrivKey
+///
+/// A BLS private key as a 32 byte big-endian scalar. The key is zeroized on drop, compared in
+/// constant time and never printed by `Debug`. Build one with `try_from_bytes`, and prefer
+/// passing `&PrivKey` around over copying `as_bytes`, as copies are not zeroized automatically.
+pub struct PrivKey([u8; 32]);
+
+impl Zeroize for PrivKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for PrivKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for PrivKey {}
+
+impl ConstantTimeEq for PrivKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for PrivKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for PrivKey {}
+
+impl core::fmt::Debug for PrivKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("PrivKey(<redacted>)")
+    }
+}
 
 impl PrivKey {
+    /// # Try From Bytes
+    ///
+    /// Creates a private key from 32 big-endian bytes, rejecting zero and any value that is not
+    /// a canonical scalar, ie: not less than `SCALAR_ORDER`.
+    pub fn try_from_bytes(bytes: &[u8; 32]) -> Result<PrivKey, BLSError> {
+        let order = crate::SCALAR_ORDER.to_be_bytes();
+
+        // Compare big-endian bytes in constant time, so we don't leak anything about the key
+        let mut lt = Choice::from(0);
+        let mut eq = Choice::from(1);
+        for (a, b) in bytes.iter().zip(order.iter()) {
+            lt |= eq & a.ct_lt(b);
+            eq &= a.ct_eq(b);
+        }
+
+        let is_zero = bytes.ct_eq(&[0u8; 32]);
+
+        if !bool::from(lt & !is_zero) {
+            return Err(BLSError::SecretKeyError);
+        }
+
+        Ok(Self(*bytes))
+    }
+
+    /// # From Random
+    ///
+    /// Samples a private key uniformly from [1, r) where r is `SCALAR_ORDER`.
     #[cfg(not(target_os = "solana"))]
     pub fn from_random() -> PrivKey {
-
         loop {
             let mut bytes = [0u8; 32];
-            
+
             rand::thread_rng().fill_bytes(&mut bytes);
-            
-            let num = dashu::integer::UBig::from_be_bytes(&bytes);
-            
-            if num < crate::MODULUS {
-                return Self(bytes);
+
+            // r is a 254 bit number, so masking the top two bits keeps rejection sampling cheap
+            // without introducing bias
+            bytes[0] &= 0x3f;
+
+            let privkey = Self::try_from_bytes(&bytes);
+            bytes.zeroize();
+
+            if let Ok(privkey) = privkey {
+                return privkey;
             }
         }
     }
@@ -28,15 +107,69 @@ impl PrivKey {
     pub fn sign<H: HashToCurve, T: AsRef<[u8]>>(&self, message: T) -> Result<G1Point, BLSError> {
         let point = H::try_hash_to_curve::<T>(message)?;
 
-        let input = [&point.0[..], &self.0[..]].concat();
+        let mut input = [&point.0[..], &self.0[..]].concat();
+        let result = alt_bn128_multiplication(&input);
+        input.zeroize();
 
         let mut g1_sol_uncompressed = [0x00u8; 64];
-        g1_sol_uncompressed.clone_from_slice(
-            &alt_bn128_multiplication(&input).map_err(|_| BLSError::BLSSigningError)?,
-        );
+        g1_sol_uncompressed.clone_from_slice(&result.map_err(|_| BLSError::BLSSigningError)?);
 
         Ok(G1Point(g1_sol_uncompressed))
     }
+
+    /// # Sign G2
+    ///
+    /// Signs a message in G2, for use with the minimal public key size variant where public keys
+    /// live in G1. Verify the result with `G1Point::verify_signature`.
+    pub fn sign_g2<H: HashToCurveG2, T: AsRef<[u8]>>(&self, message: T) -> Result<G2Point, BLSError> {
+        let point = H::try_hash_to_curve_g2::<T>(message)?.to_affine()?;
+
+        let mut secret_key = self.to_scalar()?;
+        let signature = G2Point::from_affine(&(point * secret_key).into());
+        secret_key.zeroize();
+
+        signature.map_err(|_| BLSError::BLSSigningError)
+    }
+
+    /// Converts this private key into an arkworks scalar. Callers are responsible for zeroizing
+    /// the returned scalar once they are done with it.
+    pub(crate) fn to_scalar(&self) -> Result<Fr, BLSError> {
+        let mut pk = self.0;
+        pk.reverse();
+
+        let secret_key = Fr::deserialize_compressed(&pk[..]).map_err(|_| BLSError::SecretKeyError);
+        pk.zeroize();
+
+        secret_key
+    }
+
+    /// Converts an arkworks scalar into a private key, rejecting zero.
+    #[cfg(not(target_os = "solana"))]
+    pub(crate) fn from_scalar(scalar: &Fr) -> Result<PrivKey, BLSError> {
+        let mut bytes = crate::threshold::scalar_to_bytes(scalar);
+        let privkey = Self::try_from_bytes(&bytes);
+        bytes.zeroize();
+        privkey
+    }
+
+    /// # As Bytes
+    ///
+    /// Returns the 32 big-endian bytes of this private key. Copies of these bytes are not
+    /// zeroized automatically.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// # Prove Possession
+    ///
+    /// Signs our own compressed G2 public key, hashed with `H::try_hash_to_curve_pop` rather
+    /// than the signing hash. Registries should only aggregate public keys whose proof of
+    /// possession has been verified, which prevents rogue-key attacks against aggregate
+    /// signatures.
+    pub fn prove_possession<H: HashToCurve>(&self) -> Result<G1Point, BLSError> {
+        let pubkey = G2CompressedPoint::try_from(self)?;
+        self.sign::<Pop<H>, &[u8]>(&pubkey.0)
+    }
 }
 
 #[cfg(all(test, not(target_os = "solana")))]
@@ -44,6 +177,7 @@ mod test {
     use crate::{g1_point::G1CompressedPoint, schemes::sha256_normalized::Sha256Normalized, G1Point, G2Point};
 
     use super::PrivKey;
+    use crate::{BLSError, SCALAR_ORDER};
 
     #[test]
     fn sign() {
@@ -59,6 +193,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn sign_random() {
         let message = b"sample";
         let privkey = PrivKey::from_random();
@@ -70,6 +205,51 @@ mod test {
         assert!(pubkey.verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, message).is_ok());
     }
 
+    #[test]
+    fn try_from_bytes() {
+        assert_eq!(PrivKey::try_from_bytes(&[0u8; 32]).err(), Some(BLSError::SecretKeyError));
+
+        let mut order = [0u8; 32];
+        order.clone_from_slice(&SCALAR_ORDER.to_be_bytes());
+        assert_eq!(PrivKey::try_from_bytes(&order).err(), Some(BLSError::SecretKeyError));
+        assert_eq!(PrivKey::try_from_bytes(&[0xff; 32]).err(), Some(BLSError::SecretKeyError));
+
+        // r - 1 is the largest valid key
+        order[31] -= 1;
+        assert_eq!(PrivKey::try_from_bytes(&order).unwrap().0, order);
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert!(PrivKey::try_from_bytes(&one).is_ok());
+    }
+
+    #[test]
+    fn constant_time_eq_and_redacted_debug() {
+        let privkey = PrivKey::from_random();
+        let copy = PrivKey(privkey.0);
+
+        assert_eq!(privkey, copy);
+        assert_ne!(privkey, PrivKey::from_random());
+        assert_eq!("PrivKey(<redacted>)", format!("{:?}", privkey));
+    }
+
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut privkey = PrivKey::from_random();
+        privkey.zeroize();
+        assert_eq!([0u8; 32], privkey.0);
+    }
+
+    #[test]
+    fn from_random_is_canonical() {
+        for _ in 0..64 {
+            let privkey = PrivKey::from_random();
+            assert!(PrivKey::try_from_bytes(&privkey.0).is_ok());
+        }
+    }
+
     #[test]
     fn sign_compressed() {
         let privkey = PrivKey([
@@ -0,0 +1,94 @@
+use dashu::integer::UBig;
+use solana_bn254::compression::prelude::alt_bn128_g1_decompress;
+
+use crate::{constants::MODULUS, errors::BLSError, g1_point::G1Point};
+
+use super::HashToCurve;
+
+/// # Keccak256
+///
+/// Same construction as `Sha256`, but using Keccak-256 so the hash can be reproduced by EVM
+/// verifiers using the `0x06/0x07/0x08` precompiles. For counter `n` in `0..255` we take
+/// `x = keccak256(message || n) mod p` and return the first `(x, y)` on the curve, where `y` is
+/// the smaller of the two square roots of `x^3 + 3`.
+///
+/// On-chain this uses the `sol_keccak256` syscall, off-chain it falls back to `sha3`.
+pub struct Keccak256;
+
+impl HashToCurve for Keccak256 {
+    fn try_hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+        (0..255)
+            .find_map(|n: u8| {
+                // Create a hash
+                let hash = solana_nostd_keccak::hashv(&[message.as_ref(), &[n]]);
+
+                // Convert hash to a Ubig for Bigint operations
+                let hash_ubig = UBig::from_be_bytes(&hash) % &MODULUS;
+
+                // Left pad x to 32 bytes so it matches a uint256 on the EVM
+                let x_bytes = hash_ubig.to_be_bytes();
+                let mut x = [0u8; 32];
+                x[32 - x_bytes.len()..].clone_from_slice(&x_bytes);
+
+                // Decompress the point
+                match alt_bn128_g1_decompress(&x) {
+                    Ok(p) => Some(G1Point(p)),
+                    Err(_) => None,
+                }
+            })
+            .ok_or(BLSError::HashToCurveError)
+    }
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use ark_bn254::Fq;
+    use ark_ff::{Field, PrimeField};
+
+    use super::Keccak256;
+    use crate::{G1Point, G2Point, HashToCurve, Keccak256Normalized, PrivKey};
+
+    #[test]
+    fn keccak256_empty() {
+        // Make sure we are using Keccak-256 and not SHA3-256
+        assert_eq!(
+            [0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0, 0xe5, 0x00, 0xb6, 0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70],
+            solana_nostd_keccak::hash(b"")
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_matches_evm_construction() {
+        let message = b"sample";
+        let point = Keccak256::try_hash_to_curve(message).unwrap();
+
+        // Reproduce the search the same way an EVM verifier would
+        let x = (0..255u8)
+            .map(|n| Fq::from_be_bytes_mod_order(&solana_nostd_keccak::hashv(&[message, &[n]])))
+            .find(|x| !(x.square() * x + Fq::from(3u8)).legendre().is_qnr())
+            .unwrap();
+        let y = Fq::from_be_bytes_mod_order(&point.0[32..]);
+
+        assert_eq!(x, Fq::from_be_bytes_mod_order(&point.0[..32]));
+        assert_eq!(y.square(), x.square() * x + Fq::from(3u8));
+        assert!(y <= -y);
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let privkey = PrivKey::from_random();
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+
+        let signature = privkey.sign::<Keccak256, &[u8]>(b"sample").expect("Failed to sign");
+        assert!(pubkey
+            .verify_signature::<Keccak256, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+
+        let signature = privkey
+            .sign::<Keccak256Normalized, &[u8]>(b"sample")
+            .expect("Failed to sign");
+        assert!(pubkey
+            .verify_signature::<Keccak256Normalized, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+    }
+}
@@ -0,0 +1,45 @@
+use dashu::integer::UBig;
+use solana_bn254::compression::prelude::alt_bn128_g1_decompress;
+
+use crate::{constants::MODULUS, errors::BLSError, g1_point::G1Point};
+
+use super::{HashToCurve, NORMALIZE_MODULUS};
+
+/// # Keccak256 Normalized
+///
+/// Same construction as `Sha256Normalized`, but using Keccak-256 so the hash can be reproduced
+/// by EVM verifiers. Hashes at or above `NORMALIZE_MODULUS` are skipped to avoid modulo bias
+/// before reducing by `p`.
+pub struct Keccak256Normalized;
+
+impl HashToCurve for Keccak256Normalized {
+    fn try_hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+        (0..255)
+            .find_map(|n: u8| {
+                // Create a hash
+                let hash = solana_nostd_keccak::hashv(&[message.as_ref(), &[n]]);
+
+                // Convert hash to a Ubig for Bigint operations
+                let hash_ubig = UBig::from_be_bytes(&hash);
+
+                // Check if the hash is higher than our normalization modulus of Fq * 5
+                if hash_ubig >= NORMALIZE_MODULUS {
+                    return None;
+                }
+
+                let modulus_ubig = hash_ubig % &MODULUS;
+
+                // Left pad x to 32 bytes so it matches a uint256 on the EVM
+                let x_bytes = modulus_ubig.to_be_bytes();
+                let mut x = [0u8; 32];
+                x[32 - x_bytes.len()..].clone_from_slice(&x_bytes);
+
+                // Decompress the point
+                match alt_bn128_g1_decompress(&x) {
+                    Ok(p) => Some(G1Point(p)),
+                    Err(_) => None,
+                }
+            })
+            .ok_or(BLSError::HashToCurveError)
+    }
+}
@@ -6,3 +6,18 @@ pub use sha256::*;
 
 pub mod sha256_normalized;
 pub use sha256_normalized::*;
+
+pub mod keccak256;
+pub use keccak256::*;
+
+pub mod keccak256_normalized;
+pub use keccak256_normalized::*;
+
+pub mod sha256_svdw;
+pub use sha256_svdw::*;
+
+pub mod sha256_g2;
+pub use sha256_g2::*;
+
+pub mod with_dst;
+pub use with_dst::*;
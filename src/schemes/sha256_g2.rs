@@ -0,0 +1,37 @@
+use ark_bn254::{Fq, Fq2, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::PrimeField;
+
+use crate::{errors::BLSError, g2_point::G2Point};
+
+use super::HashToCurveG2;
+
+/// # Sha256 G2
+///
+/// Try-and-increment hash to curve for G2. For counter `n` in `0..255` we build
+/// `x = sha256(message || n || 0) + sha256(message || n || 1) * u` in Fq2, take the first `x` on
+/// the twist and clear the cofactor to land in the prime-order subgroup.
+pub struct Sha256G2;
+
+impl HashToCurveG2 for Sha256G2 {
+    fn try_hash_to_curve_g2<T: AsRef<[u8]>>(message: T) -> Result<G2Point, BLSError> {
+        (0..255)
+            .find_map(|n: u8| {
+                // Create a hash for each coefficient of x
+                let c0 = solana_nostd_sha256::hashv(&[message.as_ref(), &[n], &[0]]);
+                let c1 = solana_nostd_sha256::hashv(&[message.as_ref(), &[n], &[1]]);
+
+                let x = Fq2::new(Fq::from_be_bytes_mod_order(&c0), Fq::from_be_bytes_mod_order(&c1));
+
+                // Find a point on the twist and clear the cofactor
+                let point = G2Affine::get_point_from_x_unchecked(x, false)?.clear_cofactor();
+
+                if point.is_zero() {
+                    return None;
+                }
+
+                G2Point::from_affine(&point).ok()
+            })
+            .ok_or(BLSError::HashToCurveError)
+    }
+}
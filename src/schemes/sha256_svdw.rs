@@ -0,0 +1,285 @@
+use ark_bn254::Fq;
+use ark_ff::{BigInteger, Field, MontFp, PrimeField, Zero};
+use solana_bn254::prelude::alt_bn128_addition;
+
+use crate::{errors::BLSError, g1_point::G1Point};
+
+use core::marker::PhantomData;
+
+use super::{DomainSeparationTag, HashToCurve};
+
+/// Default domain separation tag for `Sha256Svdw`, following the RFC 9380 suite naming of
+/// `BN254G1_XMD:SHA-256_SVDW_RO_` with the BLS `NUL_` (basic scheme) suffix.
+pub const SHA256_SVDW_DST: &[u8] = b"BLS_SIG_BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+
+/// Domain separation tag for proofs of possession with `Sha256Svdw`, following the BLS `POP_`
+/// (proof of possession scheme) suffix so proofs never share a hash with signatures.
+pub const SHA256_SVDW_POP_DST: &[u8] = b"BLS_POP_BN254G1_XMD:SHA-256_SVDW_RO_POP_";
+
+/// The default `DomainSeparationTag` for `Sha256Svdw`, see `SHA256_SVDW_DST`.
+pub struct Sha256SvdwDst;
+
+impl DomainSeparationTag for Sha256SvdwDst {
+    const DST: &'static [u8] = SHA256_SVDW_DST;
+}
+
+/// Length in bytes of each field element drawn by `hash_to_field`, L = ceil((ceil(log2(p)) + k) / 8)
+/// with k = 128 bits of security.
+const L: usize = 48;
+
+/// Z = 1 is the SVDW parameter for BN254 G1 (y^2 = x^3 + 3)
+const Z: Fq = MontFp!("1");
+/// B = 3
+const B: Fq = MontFp!("3");
+/// c1 = g(Z) = Z^3 + B
+const C1: Fq = MontFp!("4");
+/// c2 = -Z / 2
+const C2: Fq = MontFp!("10944121435919637611123202872628637544348155578648911831344518947322613104291");
+/// c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A)), chosen such that sgn0(c3) == 0
+const C3: Fq = MontFp!("8815841940592487685674414971303048083897117035520822607866");
+/// c4 = -4 * g(Z) / (3 * Z^2 + 4 * A)
+const C4: Fq = MontFp!("7296080957279758407415468581752425029565437052432607887563012631548408736189");
+
+/// # Sha256 SVDW
+///
+/// RFC 9380 compliant hash to curve for BN254 G1 using `expand_message_xmd` with SHA-256 and
+/// the Shallue-van de Woestijne map (suite `BN254G1_XMD:SHA-256_SVDW_RO_`). Unlike `Sha256`
+/// and `Sha256Normalized` it never fails and does not loop, and it is interoperable with other
+/// BN254 BLS implementations using the same suite and DST.
+///
+/// The DST is supplied through `D`, so each application should define its own
+/// `DomainSeparationTag` to make its signatures unusable in any other domain.
+pub struct Sha256Svdw<D: DomainSeparationTag = Sha256SvdwDst>(PhantomData<D>);
+
+impl<D: DomainSeparationTag> HashToCurve for Sha256Svdw<D> {
+    fn try_hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+        hash_to_curve_svdw(message.as_ref(), D::DST)
+    }
+
+    fn try_hash_to_curve_pop<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+        hash_to_curve_svdw(message.as_ref(), SHA256_SVDW_POP_DST)
+    }
+}
+
+/// # Hash To Curve SVDW
+///
+/// Implements `hash_to_curve` from RFC 9380 section 3 for BN254 G1 with an arbitrary DST:
+/// the message is expanded into two field elements which are each mapped to the curve and
+/// summed. BN254 G1 has cofactor 1, so no cofactor clearing is required.
+pub fn hash_to_curve_svdw(message: &[u8], dst: &[u8]) -> Result<G1Point, BLSError> {
+    let [u0, u1] = hash_to_field(message, dst)?;
+
+    let mut input = [0u8; 128];
+    input[..64].clone_from_slice(&map_to_curve_svdw(u0));
+    input[64..].clone_from_slice(&map_to_curve_svdw(u1));
+
+    let mut point = [0u8; 64];
+    point.clone_from_slice(&alt_bn128_addition(&input).map_err(|_| BLSError::HashToCurveError)?);
+    Ok(G1Point(point))
+}
+
+/// # Expand Message XMD
+///
+/// Implements `expand_message_xmd` from RFC 9380 section 5.3.1 using SHA-256.
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, BLSError> {
+    let ell = len_in_bytes.div_ceil(32);
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(BLSError::HashToCurveError);
+    }
+
+    // DSTs longer than 255 bytes are hashed down as per section 5.3.3
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = solana_nostd_sha256::hashv(&[b"H2C-OVERSIZE-DST-", dst]);
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = solana_nostd_sha256::hashv(&[
+        &[0u8; 64],
+        message,
+        &(len_in_bytes as u16).to_be_bytes(),
+        &[0u8],
+        dst,
+        &dst_len,
+    ]);
+    let mut b_i = solana_nostd_sha256::hashv(&[&b_0, &[1u8], dst, &dst_len]);
+
+    let mut uniform_bytes = Vec::with_capacity(ell * 32);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let mut xored = [0u8; 32];
+        xored
+            .iter_mut()
+            .zip(b_0.iter().zip(b_i.iter()))
+            .for_each(|(x, (a, b))| *x = a ^ b);
+        b_i = solana_nostd_sha256::hashv(&[&xored, &[i as u8], dst, &dst_len]);
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// # Hash To Field
+///
+/// Implements `hash_to_field` from RFC 9380 section 5.2, returning two elements of Fq.
+pub fn hash_to_field(message: &[u8], dst: &[u8]) -> Result<[Fq; 2], BLSError> {
+    let uniform_bytes = expand_message_xmd(message, dst, 2 * L)?;
+    Ok([
+        Fq::from_be_bytes_mod_order(&uniform_bytes[..L]),
+        Fq::from_be_bytes_mod_order(&uniform_bytes[L..]),
+    ])
+}
+
+/// # Map To Curve SVDW
+///
+/// Implements the Shallue-van de Woestijne method from RFC 9380 section 6.6.1 for BN254 G1,
+/// returning the point as 64 big-endian bytes in the format expected by the alt_bn128 syscalls.
+pub fn map_to_curve_svdw(u: Fq) -> [u8; 64] {
+    let g = |x: Fq| x.square() * x + B;
+
+    let tv1 = u.square() * C1;
+    let tv2 = Fq::ONE + tv1;
+    let tv1 = Fq::ONE - tv1;
+    // inv0: the inverse of zero is zero
+    let tv3 = (tv1 * tv2).inverse().unwrap_or(Fq::zero());
+    let tv4 = u * tv1 * tv3 * C3;
+
+    let x1 = C2 - tv4;
+    let e1 = is_square(g(x1));
+
+    let x2 = C2 + tv4;
+    let e2 = is_square(g(x2)) && !e1;
+
+    let x3 = (tv2.square() * tv3).square() * C4 + Z;
+
+    let x = if e1 {
+        x1
+    } else if e2 {
+        x2
+    } else {
+        x3
+    };
+
+    // g(x) is guaranteed to be square for one of x1, x2 or x3
+    let mut y = g(x).sqrt().unwrap_or(Fq::zero());
+    if sgn0(u) != sgn0(y) {
+        y = -y;
+    }
+
+    let mut point = [0u8; 64];
+    point[..32].clone_from_slice(&x.into_bigint().to_bytes_be());
+    point[32..].clone_from_slice(&y.into_bigint().to_bytes_be());
+    point
+}
+
+fn is_square(x: Fq) -> bool {
+    !x.legendre().is_qnr()
+}
+
+fn sgn0(x: Fq) -> bool {
+    x.into_bigint().is_odd()
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::{expand_message_xmd, hash_to_curve_svdw, Sha256Svdw};
+    use crate::{BLSError, DomainSeparationTag, G1Point, G2Point, HashToCurve, PrivKey};
+
+    const QUUX_DST: &[u8] = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+
+    #[test]
+    fn expand_message_xmd_rfc9380() {
+        // RFC 9380 appendix K.1
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(
+            [0x68, 0xa9, 0x85, 0xb8, 0x7e, 0xb6, 0xb4, 0x69, 0x52, 0x12, 0x89, 0x11, 0xf2, 0xa4, 0x41, 0x2b, 0xbc, 0x30, 0x2a, 0x9d, 0x75, 0x96, 0x67, 0xf8, 0x7f, 0x7a, 0x21, 0xd8, 0x03, 0xf0, 0x72, 0x35],
+            expand_message_xmd(b"", dst, 0x20).unwrap()[..]
+        );
+        assert_eq!(
+            [0xd8, 0xcc, 0xab, 0x23, 0xb5, 0x98, 0x5c, 0xce, 0xa8, 0x65, 0xc6, 0xc9, 0x7b, 0x6e, 0x5b, 0x83, 0x50, 0xe7, 0x94, 0xe6, 0x03, 0xb4, 0xb9, 0x79, 0x02, 0xf5, 0x3a, 0x8a, 0x0d, 0x60, 0x56, 0x15],
+            expand_message_xmd(b"abc", dst, 0x20).unwrap()[..]
+        );
+        assert_eq!(
+            [0xab, 0xba, 0x86, 0xa6, 0x12, 0x9e, 0x36, 0x6f, 0xc8, 0x77, 0xaa, 0xb3, 0x2f, 0xc4, 0xff, 0xc7, 0x01, 0x20, 0xd8, 0x99, 0x6c, 0x88, 0xae, 0xe2, 0xfe, 0x4b, 0x32, 0xd6, 0xc7, 0xb6, 0x43, 0x7a, 0x64, 0x7e, 0x6c, 0x31, 0x63, 0xd4, 0x0b, 0x76, 0xa7, 0x3c, 0xf6, 0xa5, 0x67, 0x4e, 0xf1, 0xd8, 0x90, 0xf9, 0x5b, 0x66, 0x4e, 0xe0, 0xaf, 0xa5, 0x35, 0x9a, 0x5c, 0x4e, 0x07, 0x98, 0x56, 0x35, 0xbb, 0xec, 0xba, 0xc6, 0x5d, 0x74, 0x7d, 0x3d, 0x2d, 0xa7, 0xec, 0x2b, 0x82, 0x21, 0xb1, 0x7b, 0x0c, 0xa9, 0xdc, 0x8a, 0x1a, 0xc1, 0xc0, 0x7e, 0xa6, 0xa1, 0xe6, 0x05, 0x83, 0xe2, 0xcb, 0x00, 0x05, 0x8e, 0x77, 0xb7, 0xb7, 0x2a, 0x29, 0x84, 0x25, 0xcd, 0x1b, 0x94, 0x1a, 0xd4, 0xec, 0x65, 0xe8, 0xaf, 0xc5, 0x03, 0x03, 0xa2, 0x2c, 0x0f, 0x99, 0xb0, 0x50, 0x9b, 0x4c, 0x89, 0x5f, 0x40],
+            expand_message_xmd(b"abc", dst, 0x80).unwrap()[..]
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_empty_message() {
+        // BN254G1_XMD:SHA-256_SVDW_RO_ vector shared with gnark-crypto
+        assert_eq!(
+            [0x0a, 0x97, 0x6a, 0xb9, 0x06, 0x17, 0x0d, 0xb1, 0xf9, 0x63, 0x8d, 0x37, 0x65, 0x14, 0xdb, 0xf8, 0xc4, 0x2a, 0xef, 0x25, 0x6a, 0x54, 0xbb, 0xd4, 0x85, 0x21, 0xf2, 0x07, 0x49, 0xe5, 0x9e, 0x86, 0x02, 0x92, 0x5e, 0xad, 0x66, 0xb9, 0xe6, 0x8b, 0xfc, 0x30, 0x9b, 0x01, 0x43, 0x98, 0x64, 0x0a, 0xb5, 0x5f, 0x66, 0x19, 0xab, 0x59, 0xbc, 0x1f, 0xab, 0x22, 0x10, 0xad, 0x4c, 0x4d, 0x53, 0xd5],
+            hash_to_curve_svdw(b"", QUUX_DST).unwrap().0
+        );
+    }
+
+    #[test]
+    fn hash_to_curve_abc() {
+        // BN254G1_XMD:SHA-256_SVDW_RO_ vector shared with gnark-crypto
+        assert_eq!(
+            [0x23, 0xf7, 0x17, 0xbe, 0xe8, 0x9b, 0x10, 0x03, 0x95, 0x71, 0x39, 0xf1, 0x93, 0xe6, 0xbe, 0x7d, 0xa1, 0xdf, 0x5f, 0x13, 0x74, 0xb2, 0x6a, 0x46, 0x43, 0xb0, 0x37, 0x8b, 0x5b, 0xaf, 0x53, 0xd1, 0x04, 0x14, 0x2f, 0x82, 0x6b, 0x71, 0xee, 0x57, 0x44, 0x52, 0xdb, 0xc4, 0x7e, 0x05, 0xbc, 0x3e, 0x1a, 0x64, 0x74, 0x78, 0x40, 0x3a, 0x7b, 0xa3, 0x8b, 0x7b, 0x93, 0x94, 0x8f, 0x4e, 0x15, 0x1d],
+            hash_to_curve_svdw(b"abc", QUUX_DST).unwrap().0
+        );
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let privkey = PrivKey::from_random();
+        let signature = privkey
+            .sign::<Sha256Svdw, &[u8]>(b"sample")
+            .expect("Failed to sign");
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+        assert!(pubkey
+            .verify_signature::<Sha256Svdw, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+    }
+
+    struct Quux;
+
+    impl DomainSeparationTag for Quux {
+        const DST: &'static [u8] = QUUX_DST;
+    }
+
+    struct PriceFeed;
+
+    impl DomainSeparationTag for PriceFeed {
+        const DST: &'static [u8] = b"PRICE_FEED_BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+    }
+
+    struct GovernanceVote;
+
+    impl DomainSeparationTag for GovernanceVote {
+        const DST: &'static [u8] = b"GOVERNANCE_VOTE_BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+    }
+
+    #[test]
+    fn hash_to_curve_custom_dst() {
+        assert_eq!(
+            hash_to_curve_svdw(b"abc", QUUX_DST).unwrap().0,
+            Sha256Svdw::<Quux>::try_hash_to_curve(b"abc").unwrap().0
+        );
+    }
+
+    #[test]
+    fn signatures_do_not_cross_domains() {
+        let privkey = PrivKey::from_random();
+        let signature = privkey
+            .sign::<Sha256Svdw<PriceFeed>, &[u8]>(b"sample")
+            .expect("Failed to sign");
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+
+        assert!(pubkey
+            .verify_signature::<Sha256Svdw<PriceFeed>, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+        assert_eq!(
+            pubkey.verify_signature::<Sha256Svdw<GovernanceVote>, &[u8], G1Point>(signature, b"sample"),
+            Err(BLSError::BLSVerificationError)
+        );
+    }
+}
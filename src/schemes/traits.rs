@@ -1,4 +1,8 @@
-use crate::{errors::BLSError, g1_point::G1Point};
+use core::marker::PhantomData;
+
+use crate::{errors::BLSError, g1_point::G1Point, g2_point::G2Point, POP_DOMAIN};
+
+use super::WithDst;
 
 pub trait HashToCurve {
     /// # Try Hash To Curve
@@ -11,10 +15,80 @@ pub trait HashToCurve {
     /// - Hashing algorithm
     /// - Hash scalar normalization
     /// - Domain separation
+    ///
+    /// Schemes that support domain separation take a `DomainSeparationTag` type parameter, so the
+    /// tag is part of the scheme's type and signatures from one domain can never verify in another.
     fn try_hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError>;
+
+    /// # Try Hash To Curve PoP
+    ///
+    /// Hashes a compressed public key for a proof of possession under a domain separation tag
+    /// reserved for proofs, so no signature over an application message can double as one.
+    ///
+    /// Defaults to `WithDst<Self, PopDst>`, which only separates proofs from raw signatures over
+    /// messages not starting with `SHA256(POP_DOMAIN)`. Schemes with native DST support should
+    /// override this to hash under a dedicated proof of possession DST instead.
+    fn try_hash_to_curve_pop<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError>
+    where
+        Self: Sized,
+    {
+        WithDst::<Self, PopDst>::try_hash_to_curve(message)
+    }
+}
+
+/// Hashes with the proof of possession hash of `H`, so proofs can be produced and verified by
+/// the regular signing and verification paths.
+pub(crate) struct Pop<H: HashToCurve>(PhantomData<H>);
+
+impl<H: HashToCurve> HashToCurve for Pop<H> {
+    fn try_hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+        H::try_hash_to_curve_pop(message)
+    }
+}
+
+pub trait HashToCurveG2 {
+    /// # Try Hash To Curve G2
+    ///
+    /// The G2 counterpart of `HashToCurve`, used when signatures live in G2 and public keys in G1.
+    /// Implementations must return a point in the prime-order subgroup of G2, which means clearing
+    /// the cofactor of the twist.
+    fn try_hash_to_curve_g2<T: AsRef<[u8]>>(message: T) -> Result<G2Point, BLSError>;
+}
+
+/// # Domain Separation Tag
+///
+/// Implement this trait on a unit struct to define a domain separation tag (DST) for use with
+/// DST-aware schemes such as `Sha256Svdw<D>` or `WithDst<H, D>`, ie:
+///
+/// ```
+/// use solana_alt_bn128_bls::{DomainSeparationTag, Sha256Svdw};
+///
+/// pub struct PriceFeed;
+///
+/// impl DomainSeparationTag for PriceFeed {
+///     const DST: &'static [u8] = b"PRICE_FEED_V1_BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+/// }
+///
+/// pub type PriceFeedScheme = Sha256Svdw<PriceFeed>;
+/// ```
+pub trait DomainSeparationTag {
+    const DST: &'static [u8];
+}
+
+/// The `DomainSeparationTag` for proofs of possession in schemes without native DST support,
+/// see `POP_DOMAIN`.
+pub struct PopDst;
+
+impl DomainSeparationTag for PopDst {
+    const DST: &'static [u8] = POP_DOMAIN;
 }
 
 // Trait to represent any type that can be used as a BLS signature
 pub trait BLSSignature {
     fn to_bytes(&self) -> Result<[u8; 64], BLSError>;
+}
+
+// Trait to represent any type that can be used as a BLS signature in G2
+pub trait BLSSignatureG2 {
+    fn to_bytes(&self) -> Result<[u8; 128], BLSError>;
 }
\ No newline at end of file
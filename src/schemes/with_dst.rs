@@ -0,0 +1,66 @@
+use core::marker::PhantomData;
+
+use crate::{errors::BLSError, g1_point::G1Point};
+
+use super::{DomainSeparationTag, HashToCurve};
+
+/// # With DST
+///
+/// Adds domain separation to a scheme that has no native support for it, such as `Sha256` or
+/// `Sha256Normalized`, by hashing `SHA256(DST) || message` with the inner scheme `H`.
+///
+/// This separates domains from each other, but not from `H` itself: a raw `H` signature over a
+/// message that starts with `SHA256(DST)` is also valid under `WithDst<H, D>`. Keys that sign
+/// arbitrary messages with raw `H` should use a scheme with native DST support instead, such as
+/// `Sha256Svdw<D>`, which should also be parameterized directly rather than wrapped.
+pub struct WithDst<H: HashToCurve, D: DomainSeparationTag>(PhantomData<(H, D)>);
+
+impl<H: HashToCurve, D: DomainSeparationTag> HashToCurve for WithDst<H, D> {
+    fn try_hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+        // The fixed length digest keeps the encoding injective across DSTs, and keeps the DST
+        // itself out of the signed bytes, so readable messages never start with it
+        let dst_hash = solana_nostd_sha256::hashv(&[D::DST]);
+        H::try_hash_to_curve([&dst_hash[..], message.as_ref()].concat())
+    }
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::WithDst;
+    use crate::{BLSError, DomainSeparationTag, G1Point, G2Point, PrivKey, Sha256Normalized};
+
+    struct PriceFeed;
+
+    impl DomainSeparationTag for PriceFeed {
+        const DST: &'static [u8] = b"PRICE_FEED";
+    }
+
+    struct GovernanceVote;
+
+    impl DomainSeparationTag for GovernanceVote {
+        const DST: &'static [u8] = b"GOVERNANCE_VOTE";
+    }
+
+    #[test]
+    fn signatures_do_not_cross_domains() {
+        let privkey = PrivKey::from_random();
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+
+        let signature = privkey
+            .sign::<WithDst<Sha256Normalized, PriceFeed>, &[u8]>(b"sample")
+            .expect("Failed to sign");
+
+        assert!(pubkey
+            .verify_signature::<WithDst<Sha256Normalized, PriceFeed>, &[u8], G1Point>(signature, b"sample")
+            .is_ok());
+        assert_eq!(
+            pubkey
+                .verify_signature::<WithDst<Sha256Normalized, GovernanceVote>, &[u8], G1Point>(signature, b"sample"),
+            Err(BLSError::BLSVerificationError)
+        );
+        assert_eq!(
+            pubkey.verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, b"sample"),
+            Err(BLSError::BLSVerificationError)
+        );
+    }
+}
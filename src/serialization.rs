@@ -0,0 +1,203 @@
+use core::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{BLSError, G1CompressedPoint, G1Point, G2CompressedPoint, G2Point, PrivKey};
+
+/// Serializes `bytes` as a hex string in human-readable formats and as raw bytes otherwise. The
+/// hex string is zeroized once written, as it may hold a private key.
+///
+/// Hex is the only human-readable encoding. Callers that need base58 or base64 should encode the
+/// raw bytes themselves, as a second encoding would need a way to pick one per field.
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&Zeroizing::new(hex::encode(bytes)))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Deserializes exactly `N` bytes from a hex string in human-readable formats and from raw
+/// bytes otherwise.
+fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::<N>)
+    }
+}
+
+/// Maps a validation failure into a deserialization error.
+fn invalid<E: de::Error>(error: BLSError) -> E {
+    E::custom(format_args!("{:?}", error))
+}
+
+struct BytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes or a hex string of {} characters", N, N * 2)
+    }
+
+    // Errors never echo the input, so a malformed private key is not leaked into logs
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<[u8; N], E> {
+        let mut bytes = [0u8; N];
+        hex::decode_to_slice(value, &mut bytes)
+            .map_err(|_| E::invalid_value(Unexpected::Other("malformed hex string"), &self))?;
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<[u8; N], E> {
+        value
+            .try_into()
+            .map_err(|_| E::invalid_length(value.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; N], A::Error> {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        Ok(bytes)
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for a point, which is validated with its
+/// `try_from_bytes` when deserialized. The identity is accepted so that anything we serialize,
+/// such as the signature of an empty `WeightedAggregate`, deserializes again. Signature
+/// verification rejects it on its own.
+macro_rules! impl_serde {
+    ($type:ident, $len:literal) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_bytes(&self.0, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let point = $type(deserialize_bytes::<D, $len>(deserializer)?);
+                if point.is_identity() {
+                    return Ok(point);
+                }
+                $type::try_from_bytes(&point.0).map_err(invalid)
+            }
+        }
+    };
+}
+
+impl_serde!(G1Point, 64);
+impl_serde!(G1CompressedPoint, 32);
+impl_serde!(G2Point, 128);
+impl_serde!(G2CompressedPoint, 64);
+
+impl Serialize for PrivKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PrivKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut bytes = deserialize_bytes::<D, 32>(deserializer)?;
+        let privkey = PrivKey::try_from_bytes(&bytes);
+        bytes.zeroize();
+        privkey.map_err(invalid)
+    }
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use crate::{
+        BLSError, G1CompressedPoint, G1Point, G2CompressedPoint, G2Point, PrivKey,
+        Sha256Normalized,
+    };
+
+    #[test]
+    fn roundtrip() {
+        let privkey = PrivKey::from_random();
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+        let compressed_pubkey = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
+        let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+        let compressed_signature = G1CompressedPoint::try_from(signature).unwrap();
+
+        // Hex strings in human-readable formats
+        let json = serde_json::to_string(&pubkey).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(pubkey.0)));
+        assert_eq!(serde_json::from_str::<G2Point>(&json).unwrap().0, pubkey.0);
+
+        let json = serde_json::to_string(&privkey).unwrap();
+        assert_eq!(serde_json::from_str::<PrivKey>(&json).unwrap(), privkey);
+
+        let json = serde_json::to_string(&compressed_signature).unwrap();
+        assert_eq!(
+            serde_json::from_str::<G1CompressedPoint>(&json).unwrap().0,
+            compressed_signature.0
+        );
+
+        // Raw bytes in binary formats
+        let bytes = bincode::serialize(&signature).unwrap();
+        assert_eq!(bytes[8..], signature.0);
+        assert_eq!(
+            bincode::deserialize::<G1Point>(&bytes).unwrap().0,
+            signature.0
+        );
+
+        let bytes = bincode::serialize(&compressed_pubkey).unwrap();
+        assert_eq!(
+            bincode::deserialize::<G2CompressedPoint>(&bytes).unwrap().0,
+            compressed_pubkey.0
+        );
+    }
+
+    #[test]
+    fn identity_roundtrip() {
+        // The identity deserializes like any other point, but is never a valid signature
+        let json = serde_json::to_string(&G2Point::identity()).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode([0u8; 128])));
+        assert!(serde_json::from_str::<G2Point>(&json).unwrap().is_identity());
+
+        let bytes = bincode::serialize(&G1CompressedPoint::identity()).unwrap();
+        let signature = bincode::deserialize::<G1CompressedPoint>(&bytes).unwrap();
+        assert!(signature.is_identity());
+
+        let pubkey = G2Point::try_from(&PrivKey::from_random()).expect("Invalid private key");
+        assert_eq!(
+            pubkey.verify_signature::<Sha256Normalized, &[u8], G1CompressedPoint>(signature, b"sample"),
+            Err(BLSError::G1PointIdentityError)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        // Unreduced coordinates and invalid keys fail validation
+        let unreduced = format!("\"{}\"", hex::encode([0xffu8; 64]));
+        assert!(serde_json::from_str::<G1Point>(&unreduced).is_err());
+        let zero = format!("\"{}\"", hex::encode([0u8; 32]));
+        assert!(serde_json::from_str::<PrivKey>(&zero).is_err());
+        assert!(
+            bincode::deserialize::<PrivKey>(&bincode::serialize(&[0u8; 32][..]).unwrap()).is_err()
+        );
+
+        // Malformed hex and wrong lengths
+        assert!(serde_json::from_str::<G1CompressedPoint>("\"zz\"").is_err());
+        let short = format!("\"{}\"", hex::encode([1u8; 31]));
+        assert!(serde_json::from_str::<PrivKey>(&short).is_err());
+        assert!(
+            bincode::deserialize::<G1Point>(&bincode::serialize(&[1u8; 63][..]).unwrap()).is_err()
+        );
+    }
+}
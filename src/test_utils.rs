@@ -0,0 +1,81 @@
+use crate::{G1Point, G2CompressedPoint, G2Point, PrivKey, Sha256Normalized};
+
+/// # TEST_PRIVKEY
+/// The private key of the crate's known-answer tests.
+pub const TEST_PRIVKEY: [u8; 32] = [
+    0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4, 0x5c, 0xf8,
+    0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe, 0xba, 0xaf, 0xb5, 0xc9,
+];
+
+/// # Test Privkey
+///
+/// Returns the deterministic test key at `index`, where index `0` is `TEST_PRIVKEY`. Other
+/// indices bump its leading bytes, which keeps every key distinct and below `SCALAR_ORDER`.
+pub fn test_privkey(index: u8) -> PrivKey {
+    let mut bytes = TEST_PRIVKEY;
+    bytes[0] += index % 15;
+    bytes[1] ^= index / 15;
+    PrivKey::try_from_bytes(&bytes).expect("Invalid test key")
+}
+
+/// # Test Message
+///
+/// Returns a distinct price update message for `index`.
+pub fn test_message(index: u64) -> Vec<u8> {
+    [&index.to_le_bytes()[..], b"BTCUSD<"].concat()
+}
+
+/// # Test Signer
+///
+/// A deterministic test key together with its public key, signing with `Sha256Normalized`.
+pub struct TestSigner {
+    pub privkey: PrivKey,
+    pub pubkey: G2Point,
+}
+
+impl TestSigner {
+    pub fn new(index: u8) -> Self {
+        let privkey = test_privkey(index);
+        let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+        Self { privkey, pubkey }
+    }
+
+    pub fn compressed_pubkey(&self) -> G2CompressedPoint {
+        G2CompressedPoint::try_from(&self.privkey).expect("Invalid private key")
+    }
+
+    pub fn sign(&self, message: &[u8]) -> G1Point {
+        self.privkey
+            .sign::<Sha256Normalized, &[u8]>(message)
+            .expect("Failed to sign")
+    }
+
+    pub fn prove_possession(&self) -> G1Point {
+        self.privkey
+            .prove_possession::<Sha256Normalized>()
+            .expect("Failed to prove possession")
+    }
+}
+
+/// # Test Signers
+///
+/// Returns `n` test signers, with indices `0..n`.
+pub fn test_signers(n: u8) -> Vec<TestSigner> {
+    (0..n).map(TestSigner::new).collect()
+}
+
+/// # Test Triples
+///
+/// Returns the public keys, messages and signatures of `n` test signers, each signing its own
+/// `test_message`.
+pub fn test_triples(n: u8) -> (Vec<G2Point>, Vec<Vec<u8>>, Vec<G1Point>) {
+    let signers = test_signers(n);
+    let messages: Vec<Vec<u8>> = (0..n as u64).map(test_message).collect();
+    let signatures = signers
+        .iter()
+        .zip(messages.iter())
+        .map(|(signer, message)| signer.sign(message))
+        .collect();
+    let pubkeys = signers.iter().map(|signer| signer.pubkey).collect();
+    (pubkeys, messages, signatures)
+}
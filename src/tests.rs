@@ -1,14 +1,23 @@
+#![cfg_attr(not(test), allow(unused_imports))]
+
+use num::{CheckedAdd, CheckedSub};
+
 use crate::{
-    G1Point, G1CompressedPoint, G2Point, G2CompressedPoint, PrivKey, Sha256Normalized,
+    BLSError, G1Point, G1CompressedPoint, G2Point, G2CompressedPoint, PrivKey, Sha256G2, Sha256Normalized,
+    Sha256Svdw, G1_MINUS_ONE, G2_MINUS_ONE, POP_DOMAIN,
 };
 
+#[cfg(test)]
+use crate::test_utils::{test_privkey, test_signers};
+
 #[test]
 fn keygen_g1_compressed() {
-    let privkey = PrivKey([
+    let privkey = PrivKey::try_from_bytes(&[
         0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
+    ])
+    .unwrap();
 
     let pubkey = G1CompressedPoint::try_from(privkey).expect("Invalid private key");
 
@@ -20,11 +29,12 @@ fn keygen_g1_compressed() {
 
 #[test]
 fn keygen_g1_uncompressed() {
-    let privkey = PrivKey([
+    let privkey = PrivKey::try_from_bytes(&[
         0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
+    ])
+    .unwrap();
 
     let pubkey = G1Point::try_from(privkey).expect("Invalid private key");
 
@@ -32,24 +42,28 @@ fn keygen_g1_uncompressed() {
 }
 
 #[test]
+#[allow(clippy::useless_conversion)]
 fn perps_aggregation() {
     let msg = [&50_000u64.to_le_bytes()[..], b"BTCUSD<"].concat();
 
-    let privkey_1 = PrivKey([
+    let privkey_1 = PrivKey::try_from_bytes(&[
         0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
-    let privkey_2 = PrivKey([
+    ])
+    .unwrap();
+    let privkey_2 = PrivKey::try_from_bytes(&[
         0x22, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
-    let privkey_3 = PrivKey([
+    ])
+    .unwrap();
+    let privkey_3 = PrivKey::try_from_bytes(&[
         0x23, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
+    ])
+    .unwrap();
 
     let sig_1 = privkey_1.sign::<Sha256Normalized, &[u8]>(&msg).unwrap();
     let sig_2 = privkey_2.sign::<Sha256Normalized, &[u8]>(&msg).unwrap();
@@ -70,22 +84,24 @@ fn perps_aggregation() {
 
 #[test]
 fn keygen_g2_compressed() {
-    let privkey = PrivKey([
+    let privkey = PrivKey::try_from_bytes(&[
         0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
+    ])
+    .unwrap();
     let pubkey = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
     assert_eq!([0x8b, 0x1a, 0xc6, 0x3e, 0x24, 0x4f, 0xa4, 0x19, 0x78, 0xf2, 0x84, 0xb4, 0x69, 0xa6, 0xcb, 0xe4, 0xa8, 0xba, 0xeb, 0x71, 0x06, 0x30, 0xad, 0xcc, 0xf6, 0x9b, 0x27, 0xd4, 0xbd, 0x12, 0xf5, 0x76, 0x1e, 0x88, 0xed, 0x4a, 0xeb, 0xd8, 0x43, 0x85, 0x3b, 0xf0, 0x24, 0x9c, 0x7c, 0x2b, 0x37, 0xfb, 0xb0, 0xd1, 0x77, 0xdb, 0x37, 0xe6, 0xab, 0x29, 0xd8, 0x9d, 0x4e, 0x29, 0x72, 0xdf, 0xff, 0x24], pubkey.0);
 }
 
 #[test]
 fn keygen_g2_uncompressed() {
-    let privkey = PrivKey([
+    let privkey = PrivKey::try_from_bytes(&[
         0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
+    ])
+    .unwrap();
 
     let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
 
@@ -94,11 +110,12 @@ fn keygen_g2_uncompressed() {
 
 #[test]
 fn signature_verification() {
-    let privkey = PrivKey([
+    let privkey = PrivKey::try_from_bytes(&[
         0x21, 0x6f, 0x05, 0xb4, 0x64, 0xd2, 0xca, 0xb2, 0x72, 0x95, 0x4c, 0x66, 0x0d, 0xd4,
         0x5c, 0xf8, 0xab, 0x0b, 0x26, 0x13, 0x65, 0x4d, 0xcc, 0xc7, 0x4c, 0x11, 0x55, 0xfe,
         0xba, 0xaf, 0xb5, 0xc9,
-    ]);
+    ])
+    .unwrap();
 
     let signature = privkey
         .sign::<Sha256Normalized, &str>("sample")
@@ -112,4 +129,589 @@ fn signature_verification() {
     assert!(pubkey
         .verify_signature::<Sha256Normalized, &str, G1CompressedPoint>(signature_compressed, "sample")
         .is_ok());
-}
\ No newline at end of file
+}
+
+#[test]
+fn proof_of_possession() {
+    let privkey = test_privkey(0);
+
+    let proof = privkey
+        .prove_possession::<Sha256Normalized>()
+        .expect("Failed to prove possession");
+
+    let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+    let pubkey_compressed = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
+
+    pubkey
+        .verify_possession::<Sha256Normalized, G1Point>(proof)
+        .expect("Failed to verify proof of possession");
+    pubkey_compressed
+        .verify_possession::<Sha256Normalized, G1CompressedPoint>(
+            G1CompressedPoint::try_from(proof).expect("Failed to compress G1 point"),
+        )
+        .expect("Failed to verify proof of possession");
+
+    // A proof of possession must not double as a signature over the raw public key
+    assert_eq!(
+        pubkey.verify_signature::<Sha256Normalized, &[u8], G1Point>(proof, &pubkey_compressed.0),
+        Err(BLSError::BLSVerificationError)
+    );
+
+    // Nor may a regular signature over `POP_DOMAIN || pubkey` or `len || POP_DOMAIN || pubkey`
+    // double as a proof of possession, as proofs are hashed under the digest of their own DST
+    let forged = privkey
+        .sign::<Sha256Normalized, &[u8]>(&[POP_DOMAIN, &pubkey_compressed.0[..]].concat())
+        .expect("Failed to sign");
+    assert_eq!(
+        pubkey.verify_possession::<Sha256Normalized, G1Point>(forged),
+        Err(BLSError::BLSVerificationError)
+    );
+    let forged = privkey
+        .sign::<Sha256Normalized, &[u8]>(
+            &[&[POP_DOMAIN.len() as u8][..], POP_DOMAIN, &pubkey_compressed.0[..]].concat(),
+        )
+        .expect("Failed to sign");
+    assert_eq!(
+        pubkey.verify_possession::<Sha256Normalized, G1Point>(forged),
+        Err(BLSError::BLSVerificationError)
+    );
+
+    // Schemes with native DST support hash proofs under a dedicated DST
+    let proof = privkey
+        .prove_possession::<Sha256Svdw>()
+        .expect("Failed to prove possession");
+    pubkey_compressed
+        .verify_possession::<Sha256Svdw, G1Point>(proof)
+        .expect("Failed to verify proof of possession");
+    assert_eq!(
+        pubkey.verify_possession::<Sha256Normalized, G1Point>(proof),
+        Err(BLSError::BLSVerificationError)
+    );
+}
+
+#[test]
+fn proof_of_possession_wrong_key() {
+    let signers = test_signers(2);
+
+    let proof = signers[0].prove_possession();
+    let pubkey_2 = signers[1].compressed_pubkey();
+
+    assert_eq!(
+        pubkey_2.verify_possession::<Sha256Normalized, G1Point>(proof),
+        Err(BLSError::BLSVerificationError)
+    );
+}
+
+#[test]
+fn aggregate_verify_distinct_messages() {
+    let privkey_1 = test_privkey(0);
+    let privkey_2 = test_privkey(1);
+
+    let msg_1 = [&50_000u64.to_le_bytes()[..], b"BTCUSD<"].concat();
+    let msg_2 = [&50_001u64.to_le_bytes()[..], b"BTCUSD<"].concat();
+
+    let sig_agg = privkey_1.sign::<Sha256Normalized, &[u8]>(&msg_1).unwrap()
+        + privkey_2.sign::<Sha256Normalized, &[u8]>(&msg_2).unwrap();
+
+    let pubkeys = [
+        G2Point::try_from(&privkey_1).expect("Invalid private key"),
+        G2Point::try_from(&privkey_2).expect("Invalid private key"),
+    ];
+
+    G2Point::aggregate_verify::<Sha256Normalized, G1Point>(&pubkeys, &[&msg_1, &msg_2], sig_agg)
+        .expect("Failed to verify aggregate signature");
+
+    // Swapping the messages must fail
+    assert_eq!(
+        G2Point::aggregate_verify::<Sha256Normalized, G1Point>(&pubkeys, &[&msg_2, &msg_1], sig_agg),
+        Err(BLSError::BLSVerificationError)
+    );
+
+    // Duplicate messages are rejected
+    assert_eq!(
+        G2Point::aggregate_verify::<Sha256Normalized, G1Point>(&pubkeys, &[&msg_1, &msg_1], sig_agg),
+        Err(BLSError::DuplicateMessageError)
+    );
+
+    // Mismatched inputs are rejected
+    assert_eq!(
+        G2Point::aggregate_verify::<Sha256Normalized, G1Point>(&pubkeys, &[&msg_1], sig_agg),
+        Err(BLSError::InputLengthError)
+    );
+}
+
+#[test]
+fn g2_signature_verification() {
+    let privkey = test_privkey(0);
+
+    let signature = privkey
+        .sign_g2::<Sha256G2, &str>("sample")
+        .expect("Signature error");
+
+    let signature_compressed =
+        G2CompressedPoint::try_from(&signature).expect("Failed to compress G2 point");
+
+    let pubkey = G1CompressedPoint::try_from(privkey).expect("Invalid private key");
+
+    assert!(pubkey
+        .verify_signature::<Sha256G2, &str, G2CompressedPoint>(signature_compressed, "sample")
+        .is_ok());
+
+    assert_eq!(
+        pubkey.verify_signature::<Sha256G2, &str, G2Point>(signature, "other"),
+        Err(BLSError::BLSVerificationError)
+    );
+}
+
+#[test]
+fn g2_signature_aggregation() {
+    let msg = [&50_000u64.to_le_bytes()[..], b"BTCUSD<"].concat();
+
+    let privkey_1 = test_privkey(0);
+    let privkey_2 = test_privkey(1);
+
+    let sig_agg = privkey_1.sign_g2::<Sha256G2, &[u8]>(&msg).unwrap()
+        + privkey_2.sign_g2::<Sha256G2, &[u8]>(&msg).unwrap();
+
+    let pubkey_agg = G1Point::try_from(privkey_1).expect("Invalid private key")
+        + G1Point::try_from(privkey_2).expect("Invalid private key");
+
+    pubkey_agg
+        .verify_signature::<Sha256G2, &[u8], G2Point>(sig_agg, &msg)
+        .expect("Failed to verify signature");
+}
+
+#[test]
+fn g2_signature_validation() {
+    use ark_bn254::{Fq2, G2Affine};
+    use ark_ff::UniformRand;
+
+    let signature = test_privkey(0).sign_g2::<Sha256G2, &str>("sample").unwrap();
+    let pubkey = G1Point::try_from(test_privkey(0)).expect("Invalid private key");
+
+    // The identity pair would verify any message
+    assert_eq!(
+        G1Point::identity()
+            .verify_signature::<Sha256G2, &str, G2Point>(G2Point::identity(), "sample"),
+        Err(BLSError::G1PointIdentityError)
+    );
+    assert_eq!(
+        pubkey.verify_signature::<Sha256G2, &str, G2Point>(G2Point::identity(), "sample"),
+        Err(BLSError::G2PointIdentityError)
+    );
+
+    // Signatures on the twist but outside the prime order subgroup are rejected
+    let mut rng = rand::thread_rng();
+    let outside_subgroup = loop {
+        if let Some(point) = G2Affine::get_point_from_x_unchecked(Fq2::rand(&mut rng), false) {
+            break G2Point::from_affine(&point).unwrap();
+        }
+    };
+    assert_eq!(
+        pubkey.verify_signature::<Sha256G2, &str, G2Point>(outside_subgroup, "sample"),
+        Err(BLSError::G2PointNotInSubgroupError)
+    );
+    assert!(pubkey
+        .verify_signature::<Sha256G2, &str, G2Point>(signature, "sample")
+        .is_ok());
+}
+
+#[test]
+fn g2_affine_roundtrip() {
+    use ark_bn254::{Fr, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::UniformRand;
+
+    // A point and its negation cover both signs of y, one of which arkworks flags in the top
+    // bits of its uncompressed encoding
+    let point = (G2Affine::generator() * Fr::rand(&mut rand::thread_rng())).into_affine();
+    for point in [point, -point] {
+        let encoded = G2Point::from_affine(&point).unwrap();
+
+        // Coordinates are below p, so the top two bits of each are always clear
+        assert_eq!(encoded.0[0] & 0xc0, 0);
+        assert_eq!(encoded.0[64] & 0xc0, 0);
+        assert_eq!(encoded.to_affine().unwrap(), point);
+
+        // The encoding matches the syscall's after compressing and decompressing
+        let compressed = G2CompressedPoint::try_from(&encoded).unwrap();
+        assert_eq!(G2Point::try_from(compressed).unwrap().0, encoded.0);
+    }
+}
+
+#[test]
+fn point_negation() {
+    let mut one = [0u8; 32];
+    one[31] = 1;
+
+    // -G1 and -G2 match the negated generators used for pairing checks
+    let g1 = G1Point::try_from(PrivKey::try_from_bytes(&one).unwrap()).expect("Invalid private key");
+    assert_eq!((-g1).0, G1_MINUS_ONE);
+    assert_eq!((-(-g1)).0, g1.0);
+
+    let g2 = G2Point::try_from(&PrivKey::try_from_bytes(&one).unwrap()).expect("Invalid private key");
+    assert_eq!((-g2).0, G2_MINUS_ONE);
+    assert_eq!((-(-g2)).0, g2.0);
+
+    // Negating a compressed point matches negating before compression
+    let privkey = PrivKey::from_random();
+    let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+    assert_eq!(
+        (-G2CompressedPoint::try_from(&pubkey).unwrap()).0,
+        G2CompressedPoint::try_from(&-pubkey).unwrap().0
+    );
+
+    let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+    assert_eq!(
+        (-G1CompressedPoint::try_from(signature).unwrap()).0,
+        G1CompressedPoint::try_from(-signature).unwrap().0
+    );
+
+    // The identity is its own negation
+    assert_eq!((-G1Point([0u8; 64])).0, [0u8; 64]);
+    assert_eq!((-G2Point([0u8; 128])).0, [0u8; 128]);
+    assert_eq!((-G1CompressedPoint([0u8; 32])).0, [0u8; 32]);
+    assert_eq!((-G2CompressedPoint([0u8; 64])).0, [0u8; 64]);
+}
+
+#[test]
+fn aggregate_subtraction() {
+    let msg = [&50_000u64.to_le_bytes()[..], b"BTCUSD<"].concat();
+    let signers = test_signers(3);
+    let pubkeys: Vec<G2Point> = signers.iter().map(|s| s.pubkey).collect();
+    let signatures: Vec<G1Point> = signers.iter().map(|s| s.sign(&msg)).collect();
+
+    let pubkey_agg = pubkeys[0] + pubkeys[1] + pubkeys[2];
+    let sig_agg = signatures[0] + signatures[1] + signatures[2];
+
+    // Excluding an offline signer from both aggregates still verifies
+    let pubkey_excluded = pubkey_agg - pubkeys[2];
+    let sig_excluded = sig_agg - signatures[2];
+    assert_eq!(pubkey_excluded.0, (pubkeys[0] + pubkeys[1]).0);
+    assert!(pubkey_excluded
+        .verify_signature::<Sha256Normalized, &[u8], G1Point>(sig_excluded, &msg)
+        .is_ok());
+
+    // Compressed forms subtract the same way
+    let pubkey_compressed = G2CompressedPoint::try_from(&pubkey_agg).unwrap()
+        - G2CompressedPoint::try_from(&pubkeys[2]).unwrap();
+    assert_eq!(pubkey_compressed.0, G2CompressedPoint::try_from(&pubkey_excluded).unwrap().0);
+    let sig_compressed = G1CompressedPoint::try_from(sig_agg).unwrap()
+        - G1CompressedPoint::try_from(signatures[2]).unwrap();
+    assert_eq!(sig_compressed.0, G1CompressedPoint::try_from(sig_excluded).unwrap().0);
+
+    // Subtracting a point from itself gives the identity
+    assert_eq!(pubkey_agg.checked_sub(&pubkey_agg).unwrap().0, [0u8; 128]);
+    assert_eq!((signatures[0] - signatures[0]).0, [0u8; 64]);
+    assert_eq!(
+        (pubkeys[0] - pubkeys[0] + pubkeys[1]).0,
+        pubkeys[1].0
+    );
+}
+
+#[test]
+fn g2_scalar_multiplication() {
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let g2 = G2Point::try_from(&PrivKey::try_from_bytes(&one).unwrap()).expect("Invalid private key");
+
+    // Multiplying the generator by a private key gives its public key
+    let privkey = PrivKey::from_random();
+    let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+    assert_eq!(g2.mul_scalar(privkey.as_bytes()).unwrap().0, pubkey.0);
+    assert_eq!(
+        G2CompressedPoint::try_from(&g2).unwrap().mul_scalar(privkey.as_bytes()).unwrap().0,
+        G2CompressedPoint::try_from(&pubkey).unwrap().0
+    );
+    assert_eq!(g2.mul_scalar(&[0u8; 32]).unwrap().0, [0u8; 128]);
+
+    // A weighted aggregate signature verifies against the MSM of the public keys
+    let msg = b"sample";
+    let signers = test_signers(3);
+    let pubkeys: Vec<G2Point> = signers.iter().map(|s| s.pubkey).collect();
+    let weights: Vec<[u8; 32]> = (1..=3u8)
+        .map(|w| {
+            let mut scalar = [0u8; 32];
+            scalar[31] = w;
+            scalar
+        })
+        .collect();
+
+    let weighted_signature = signers
+        .iter()
+        .zip(weights.iter())
+        .map(|(s, w)| s.sign(msg).mul_scalar(w).unwrap())
+        .reduce(|acc, s| acc + s)
+        .unwrap();
+
+    let weighted_pubkey = G2Point::msm(&pubkeys, &weights).unwrap();
+    assert_eq!(
+        weighted_pubkey.0,
+        (pubkeys[0] + pubkeys[1] + pubkeys[1] + pubkeys[2].mul_scalar(&weights[2]).unwrap()).0
+    );
+    assert!(weighted_pubkey
+        .verify_signature::<Sha256Normalized, &[u8], G1Point>(weighted_signature, msg)
+        .is_ok());
+
+    assert_eq!(
+        G2Point::msm(&pubkeys, &weights[..2]).err(),
+        Some(BLSError::InputLengthError)
+    );
+
+    // Points off the twist are rejected, as they are on-chain
+    let mut off_curve = pubkeys[0];
+    off_curve.0[127] ^= 1;
+    assert_eq!(
+        off_curve.mul_scalar(&weights[0]).err(),
+        Some(BLSError::G2PointNotOnCurveError)
+    );
+    assert_eq!(
+        G2Point::msm(&[pubkeys[1], off_curve], &weights[..2]).err(),
+        Some(BLSError::G2PointNotOnCurveError)
+    );
+}
+
+#[test]
+fn g1_multi_scalar_multiplication() {
+    let msg = b"sample";
+    let signatures: Vec<G1Point> = test_signers(5).iter().map(|s| s.sign(msg)).collect();
+    let scalars: Vec<[u8; 32]> = (0..5).map(|_| *PrivKey::from_random().as_bytes()).collect();
+
+    // The MSM matches composing the multiplication and addition syscalls
+    let expected = signatures
+        .iter()
+        .zip(scalars.iter())
+        .map(|(s, k)| s.mul_scalar(k).unwrap())
+        .reduce(|acc, s| acc + s)
+        .unwrap();
+    assert_eq!(G1Point::msm(&signatures, &scalars).unwrap().0, expected.0);
+    assert_eq!(
+        G1CompressedPoint::try_from(signatures[0])
+            .unwrap()
+            .mul_scalar(&scalars[0])
+            .unwrap()
+            .0,
+        G1CompressedPoint::try_from(signatures[0].mul_scalar(&scalars[0]).unwrap()).unwrap().0
+    );
+
+    // Zero scalars, the identity and empty inputs contribute nothing
+    assert_eq!(
+        G1Point::msm(&[signatures[0], G1Point([0u8; 64])], &[[0u8; 32], scalars[1]])
+            .unwrap()
+            .0,
+        [0u8; 64]
+    );
+    assert_eq!(G1Point::msm(&[], &[]).unwrap().0, [0u8; 64]);
+    assert_eq!(
+        G1Point::msm(&signatures, &scalars[..4]).err(),
+        Some(BLSError::InputLengthError)
+    );
+
+    // Points off the curve are rejected off-chain, as the syscalls reject them on-chain
+    let mut off_curve = signatures[0];
+    off_curve.0[63] ^= 1;
+    assert_eq!(
+        G1Point::msm(&[signatures[1], off_curve], &scalars[..2]).err(),
+        Some(BLSError::G1PointNotOnCurveError)
+    );
+}
+
+#[test]
+fn g2_point_validation() {
+    let privkey = PrivKey::from_random();
+    let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+    let compressed = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
+
+    assert!(G2Point::try_from_bytes(&pubkey.0).is_ok());
+    assert!(G2CompressedPoint::try_from_bytes(&compressed.0).is_ok());
+    assert!(G2Point(G2_MINUS_ONE).validate().is_ok());
+
+    // The identity is not a valid public key
+    assert_eq!(
+        G2Point::try_from_bytes(&[0u8; 128]).err(),
+        Some(BLSError::G2PointIdentityError)
+    );
+    assert_eq!(
+        G2CompressedPoint::try_from_bytes(&[0u8; 64]).err(),
+        Some(BLSError::G2PointIdentityError)
+    );
+
+    // Coordinates must be reduced and the point on the twist
+    let mut unreduced = pubkey.0;
+    unreduced[..32].copy_from_slice(&[0xff; 32]);
+    assert_eq!(
+        G2Point::try_from_bytes(&unreduced).err(),
+        Some(BLSError::G2PointNotCanonicalError)
+    );
+    let mut off_curve = pubkey.0;
+    off_curve[127] ^= 1;
+    assert_eq!(
+        G2Point::try_from_bytes(&off_curve).err(),
+        Some(BLSError::G2PointNotOnCurveError)
+    );
+}
+
+#[test]
+fn g1_signature_validation() {
+    let msg = b"sample";
+    let privkey = PrivKey::from_random();
+    let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+    let signature = privkey.sign::<Sha256Normalized, &[u8]>(msg).unwrap();
+    let compressed = G1CompressedPoint::try_from(signature).unwrap();
+
+    assert!(G1Point::try_from_bytes(&signature.0).is_ok());
+    assert!(G1CompressedPoint::try_from_bytes(&compressed.0).is_ok());
+    assert!(G1Point(G1_MINUS_ONE).validate().is_ok());
+
+    assert_eq!(
+        G1CompressedPoint::try_from_bytes(&[0u8; 32]).err(),
+        Some(BLSError::G1PointIdentityError)
+    );
+    let mut unreduced = signature.0;
+    unreduced[..32].copy_from_slice(&crate::MODULUS_BYTES);
+    assert_eq!(
+        G1Point::try_from_bytes(&unreduced).err(),
+        Some(BLSError::G1PointNotCanonicalError)
+    );
+    let mut off_curve = signature.0;
+    off_curve[63] ^= 1;
+    assert_eq!(
+        G1Point::try_from_bytes(&off_curve).err(),
+        Some(BLSError::G1PointNotOnCurveError)
+    );
+
+    // The identity signature pairs to one against the identity public key, so it must be
+    // rejected before reaching the pairing
+    assert_eq!(
+        G2Point([0u8; 128]).verify_signature::<Sha256Normalized, &[u8], G1Point>(G1Point([0u8; 64]), msg),
+        Err(BLSError::G1PointIdentityError)
+    );
+    assert_eq!(
+        G2Point::aggregate_verify::<Sha256Normalized, G1Point>(core::slice::from_ref(&pubkey), &[msg], G1Point([0u8; 64])),
+        Err(BLSError::G1PointIdentityError)
+    );
+    assert_eq!(
+        pubkey.verify_signature::<Sha256Normalized, &[u8], G1Point>(G1Point(off_curve), msg),
+        Err(BLSError::G1PointNotOnCurveError)
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn cast_account_data() {
+    let pubkeys: Vec<G2CompressedPoint> = test_signers(3)
+        .iter()
+        .map(|s| s.compressed_pubkey())
+        .collect();
+    let data: Vec<u8> = pubkeys.iter().flat_map(|p| p.0).collect();
+
+    // Account data maps directly onto a slice of points and back
+    let points: &[G2CompressedPoint] = bytemuck::cast_slice(&data);
+    assert_eq!(points.len(), 3);
+    assert!(points.iter().zip(pubkeys.iter()).all(|(a, b)| a.0 == b.0));
+    assert_eq!(bytemuck::cast_slice::<G2CompressedPoint, u8>(points), &data[..]);
+
+    let signature: &G1CompressedPoint = bytemuck::from_bytes(&data[..32]);
+    assert_eq!(signature.0, data[..32]);
+    assert!(bytemuck::try_cast_slice::<u8, G2CompressedPoint>(&data[..100]).is_err());
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn borsh_roundtrip() {
+    let privkey = PrivKey::from_random();
+    let pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+    let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+
+    // Points encode as their raw bytes
+    let bytes = borsh::to_vec(&pubkey).unwrap();
+    assert_eq!(bytes, pubkey.0);
+    assert_eq!(borsh::from_slice::<G2Point>(&bytes).unwrap().0, pubkey.0);
+
+    let bytes = borsh::to_vec(&signature).unwrap();
+    assert_eq!(borsh::from_slice::<G1Point>(&bytes).unwrap().0, signature.0);
+    assert!(borsh::from_slice::<G1Point>(&bytes[..63]).is_err());
+}
+
+#[test]
+fn identity_handling() {
+    let msg = b"sample";
+    let signers = test_signers(3);
+    let pubkeys: Vec<G2Point> = signers.iter().map(|s| s.pubkey).collect();
+    let signatures: Vec<G1Point> = signers.iter().map(|s| s.sign(msg)).collect();
+
+    // The identity is neutral and the sum of a point and its negation
+    assert!(G1Point::identity().is_identity());
+    assert!(!signatures[0].is_identity());
+    assert_eq!(signatures[0] + G1Point::identity(), signatures[0]);
+    assert_eq!(G1Point::identity() + signatures[0], signatures[0]);
+    assert!((signatures[0] + -signatures[0]).is_identity());
+    assert_eq!(
+        pubkeys[0].checked_add(&G2Point::identity()),
+        Some(pubkeys[0])
+    );
+    assert_eq!(
+        G2Point::identity().checked_add(&pubkeys[0]),
+        Some(pubkeys[0])
+    );
+    assert!(pubkeys[0].checked_add(&-pubkeys[0]).unwrap().is_identity());
+    assert!((G2Point::identity() + G2Point::identity()).is_identity());
+
+    // The identity compresses to all zeros and back
+    assert_eq!(
+        G1CompressedPoint::try_from(G1Point::identity()),
+        Ok(G1CompressedPoint::identity())
+    );
+    assert_eq!(
+        G1Point::try_from(&G1CompressedPoint::identity()),
+        Ok(G1Point::identity())
+    );
+    assert_eq!(
+        G2CompressedPoint::try_from(&G2Point::identity()),
+        Ok(G2CompressedPoint::identity())
+    );
+    assert_eq!(
+        G2Point::try_from(G2CompressedPoint::identity()),
+        Ok(G2Point::identity())
+    );
+    assert!((pubkeys[0].checked_sub(&pubkeys[0]).unwrap()).is_identity());
+
+    // Empty sums are the identity
+    assert!(core::iter::empty::<G1Point>()
+        .sum::<G1Point>()
+        .is_identity());
+    assert!(core::iter::empty::<G2Point>()
+        .sum::<G2Point>()
+        .is_identity());
+    assert!(core::iter::empty::<G1CompressedPoint>()
+        .sum::<G1CompressedPoint>()
+        .is_identity());
+    assert!(core::iter::empty::<G2CompressedPoint>()
+        .sum::<G2CompressedPoint>()
+        .is_identity());
+
+    // Sums match repeated addition, in both representations
+    let signature: G1Point = signatures.iter().sum();
+    let pubkey: G2Point = pubkeys.iter().sum();
+    assert_eq!(signature, signatures[0] + signatures[1] + signatures[2]);
+    assert_eq!(pubkey, G2Point::aggregate(&pubkeys).unwrap());
+    assert_eq!(pubkeys.clone().into_iter().sum::<G2Point>(), pubkey);
+
+    let compressed_signatures: Vec<G1CompressedPoint> = signatures
+        .iter()
+        .map(|s| G1CompressedPoint::try_from(*s).unwrap())
+        .collect();
+    let compressed_pubkeys: Vec<G2CompressedPoint> = pubkeys
+        .iter()
+        .map(|p| G2CompressedPoint::try_from(p).unwrap())
+        .collect();
+    assert_eq!(
+        compressed_signatures.iter().sum::<G1CompressedPoint>(),
+        signature
+    );
+    assert_eq!(compressed_pubkeys.iter().sum::<G2CompressedPoint>(), pubkey);
+
+    assert!(pubkey
+        .verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, msg)
+        .is_ok());
+}
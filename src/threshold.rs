@@ -0,0 +1,173 @@
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, Field, PrimeField};
+#[cfg(not(target_os = "solana"))]
+use ark_ff::{UniformRand, Zero};
+use zeroize::Zeroize;
+
+use crate::{errors::BLSError, G1Point, PrivKey};
+
+/// # Secret Share
+///
+/// A Shamir share of a private key, ie: the evaluation `f(index)` of the dealer's secret
+/// polynomial. Shares sign like any other `PrivKey`, and any `t` partial signatures can be
+/// combined with `combine_partial_signatures` into a signature for the group public key.
+pub struct SecretShare {
+    pub index: u32,
+    pub key: PrivKey,
+}
+
+impl SecretShare {
+    /// # Split
+    ///
+    /// Shamir splits `privkey` into `n` shares with indices `1..=n`, any `threshold` of which can
+    /// produce a valid signature under the public key of `privkey`.
+    #[cfg(not(target_os = "solana"))]
+    pub fn split(privkey: &PrivKey, threshold: u32, n: u32) -> Result<Vec<SecretShare>, BLSError> {
+        if threshold == 0 || threshold > n {
+            return Err(BLSError::ThresholdError);
+        }
+
+        // f(x) = secret + a_1·x + ... + a_{t-1}·x^{t-1}
+        let mut coefficients = Vec::with_capacity(threshold as usize);
+        coefficients.push(privkey.to_scalar()?);
+        for _ in 1..threshold {
+            coefficients.push(Fr::rand(&mut rand::thread_rng()));
+        }
+
+        let shares = (1..=n)
+            .map(|index| {
+                let mut share = evaluate_polynomial(&coefficients, Fr::from(index));
+                let key = PrivKey::from_scalar(&share);
+                share.zeroize();
+                Ok(SecretShare { index, key: key? })
+            })
+            .collect();
+
+        coefficients.iter_mut().for_each(|c| c.zeroize());
+
+        shares
+    }
+}
+
+/// Evaluates a polynomial with the given coefficients at `x` using Horner's method.
+#[cfg(not(target_os = "solana"))]
+pub(crate) fn evaluate_polynomial(coefficients: &[Fr], x: Fr) -> Fr {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fr::zero(), |acc, coefficient| acc * x + coefficient)
+}
+
+/// Converts a scalar into 32 big-endian bytes.
+pub(crate) fn scalar_to_bytes(scalar: &Fr) -> [u8; 32] {
+    let mut scalar_bytes = scalar.into_bigint().to_bytes_be();
+    let mut bytes = [0u8; 32];
+    bytes[32 - scalar_bytes.len()..].clone_from_slice(&scalar_bytes);
+    scalar_bytes.zeroize();
+    bytes
+}
+
+/// # Lagrange Coefficients
+///
+/// Returns the Lagrange coefficients at zero for the given share indices, ie: for each index
+/// `i` the product of `j / (j - i)` over all other indices `j`. Indices must be distinct and
+/// non-zero.
+pub(crate) fn lagrange_coefficients(indices: &[u32]) -> Result<Vec<Fr>, BLSError> {
+    if indices.is_empty() {
+        return Err(BLSError::InputLengthError);
+    }
+
+    let mut sorted = indices.to_vec();
+    sorted.sort_unstable();
+    if sorted[0] == 0 || sorted.windows(2).any(|w| w[0] == w[1]) {
+        return Err(BLSError::ThresholdError);
+    }
+
+    indices
+        .iter()
+        .map(|&i| {
+            let x_i = Fr::from(i);
+            let (numerator, denominator) = indices
+                .iter()
+                .filter(|&&j| j != i)
+                .fold((Fr::ONE, Fr::ONE), |(num, den), &j| {
+                    let x_j = Fr::from(j);
+                    (num * x_j, den * (x_j - x_i))
+                });
+            denominator
+                .inverse()
+                .map(|inverse| numerator * inverse)
+                .ok_or(BLSError::ThresholdError)
+        })
+        .collect()
+}
+
+/// # Combine Partial Signatures
+///
+/// Lagrange interpolates `t` partial signatures `(index, signature)` in G1, producing the
+/// signature the group private key would have made. The result verifies against the group
+/// `G2Point` as long as at least `threshold` valid partial signatures are supplied.
+pub fn combine_partial_signatures(partials: &[(u32, G1Point)]) -> Result<G1Point, BLSError> {
+    let indices: Vec<u32> = partials.iter().map(|(index, _)| *index).collect();
+    let coefficients = lagrange_coefficients(&indices)?;
+
+    let points: Vec<G1Point> = partials.iter().map(|(_, partial)| *partial).collect();
+    let scalars: Vec<[u8; 32]> = coefficients.iter().map(scalar_to_bytes).collect();
+
+    G1Point::msm(&points, &scalars)
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::{combine_partial_signatures, SecretShare};
+    use crate::{BLSError, G1Point, G2Point, PrivKey, Sha256Normalized};
+
+    #[test]
+    fn threshold_signature() {
+        let message = b"sample";
+        let privkey = PrivKey::from_random();
+        let group_pubkey = G2Point::try_from(&privkey).expect("Invalid private key");
+
+        let shares = SecretShare::split(&privkey, 3, 5).unwrap();
+        let partials: Vec<(u32, G1Point)> = shares
+            .iter()
+            .map(|share| (share.index, share.key.sign::<Sha256Normalized, &[u8]>(message).unwrap()))
+            .collect();
+
+        // Any 3 of the 5 partial signatures produce the group signature
+        let expected = privkey.sign::<Sha256Normalized, &[u8]>(message).unwrap();
+        for subset in [[0, 1, 2], [0, 2, 4], [4, 3, 1]] {
+            let selected: Vec<(u32, G1Point)> = subset.iter().map(|&i| partials[i]).collect();
+            let signature = combine_partial_signatures(&selected).unwrap();
+            assert_eq!(expected.0, signature.0);
+            assert!(group_pubkey
+                .verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, message)
+                .is_ok());
+        }
+
+        // Fewer than 3 partial signatures do not
+        let signature = combine_partial_signatures(&partials[..2]).unwrap();
+        assert_eq!(
+            group_pubkey.verify_signature::<Sha256Normalized, &[u8], G1Point>(signature, message),
+            Err(BLSError::BLSVerificationError)
+        );
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let privkey = PrivKey::from_random();
+        assert_eq!(SecretShare::split(&privkey, 0, 5).err(), Some(BLSError::ThresholdError));
+        assert_eq!(SecretShare::split(&privkey, 6, 5).err(), Some(BLSError::ThresholdError));
+
+        let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+        assert_eq!(
+            combine_partial_signatures(&[(1, signature), (1, signature)]).err(),
+            Some(BLSError::ThresholdError)
+        );
+        assert_eq!(
+            combine_partial_signatures(&[(0, signature)]).err(),
+            Some(BLSError::ThresholdError)
+        );
+        assert_eq!(combine_partial_signatures(&[]).err(), Some(BLSError::InputLengthError));
+    }
+}
@@ -0,0 +1,208 @@
+use num::CheckedAdd;
+
+use crate::{errors::BLSError, g2_arithmetic::g2_sum, G1Point, G2Point, HashToCurve};
+
+/// # Weighted Aggregate
+///
+/// An aggregate signature over a single message together with a bitmap of which keys in an
+/// ordered key set contributed to it. Bit `i` of the bitmap (least significant bit first within
+/// each byte) is set if `pubkeys[i]` signed.
+///
+/// As every signer signs the same message, all keys in the set must have had their proof of
+/// possession verified before being admitted, otherwise a rogue key could forge the aggregate.
+#[derive(Clone)]
+pub struct WeightedAggregate {
+    /// Number of keys in the key set
+    pub n: usize,
+    pub bitmap: Vec<u8>,
+    pub signature: G1Point,
+}
+
+impl WeightedAggregate {
+    /// Creates an empty aggregate over a key set of `n` keys.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            bitmap: vec![0u8; n.div_ceil(8)],
+            signature: G1Point::identity(),
+        }
+    }
+
+    /// Adds the signature of the key at `index`, rejecting out of range or repeated signers.
+    pub fn add_signature(&mut self, index: usize, signature: &G1Point) -> Result<(), BLSError> {
+        if index >= self.n || index / 8 >= self.bitmap.len() || self.is_signer(index) {
+            return Err(BLSError::SignerBitmapError);
+        }
+
+        self.signature = self
+            .signature
+            .checked_add(signature)
+            .ok_or(BLSError::AltBN128AddError)?;
+        self.bitmap[index / 8] |= 1 << (index % 8);
+
+        Ok(())
+    }
+
+    /// Returns whether the key at `index` contributed to this aggregate.
+    pub fn is_signer(&self, index: usize) -> bool {
+        self.bitmap
+            .get(index / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    /// Returns the indices of all keys that contributed to this aggregate, in ascending order.
+    pub fn signers(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.bitmap.len() * 8).filter(|&i| self.is_signer(i))
+    }
+
+    /// Checks the aggregate is over `n` keys with a bitmap sized for them, no bits set past the
+    /// end, and at least one signer.
+    fn check_bitmap(&self, n: usize) -> Result<(), BLSError> {
+        if self.n != n
+            || self.bitmap.len() != n.div_ceil(8)
+            || self.signers().any(|i| i >= n)
+            || self.signers().next().is_none()
+        {
+            return Err(BLSError::SignerBitmapError);
+        }
+        Ok(())
+    }
+
+    /// # Aggregate Pubkey
+    ///
+    /// Sums the public keys of all signers in `pubkeys` with a single field inversion.
+    pub fn aggregate_pubkey(&self, pubkeys: &[G2Point]) -> Result<G2Point, BLSError> {
+        self.check_bitmap(pubkeys.len())?;
+
+        g2_sum(self.signers().map(|i| &pubkeys[i].0))
+            .map(G2Point)
+            .ok_or(BLSError::AltBN128AddError)
+    }
+
+    /// # Total Weight
+    ///
+    /// Sums `weights[i]` over all signers, where `weights` is ordered like the key set.
+    pub fn total_weight(&self, weights: &[u64]) -> Result<u64, BLSError> {
+        self.check_bitmap(weights.len())?;
+
+        self.signers().try_fold(0u64, |acc, i| {
+            acc.checked_add(weights[i])
+                .ok_or(BLSError::WeightOverflowError)
+        })
+    }
+
+    /// # Verify
+    ///
+    /// Verifies the aggregate signature over `message` against the aggregate public key of all
+    /// signers, and that the signers' total weight is at least `threshold`.
+    pub fn verify<H: HashToCurve, T: AsRef<[u8]>>(
+        &self,
+        pubkeys: &[G2Point],
+        weights: &[u64],
+        threshold: u64,
+        message: T,
+    ) -> Result<(), BLSError> {
+        if pubkeys.len() != weights.len() {
+            return Err(BLSError::InputLengthError);
+        }
+
+        if self.total_weight(weights)? < threshold {
+            return Err(BLSError::InsufficientWeightError);
+        }
+
+        self.aggregate_pubkey(pubkeys)?
+            .verify_signature::<H, T, G1Point>(self.signature, message)
+    }
+}
+
+#[cfg(all(test, not(target_os = "solana")))]
+mod test {
+    use super::WeightedAggregate;
+    use crate::{test_utils::test_signers, BLSError, G2Point, PrivKey, Sha256Normalized};
+
+    #[test]
+    fn weighted_aggregate() {
+        let message = b"sample";
+        let signers = test_signers(10);
+        let pubkeys: Vec<G2Point> = signers.iter().map(|s| s.pubkey).collect();
+        let weights: Vec<u64> = (1..=10).collect();
+
+        let mut aggregate = WeightedAggregate::new(pubkeys.len());
+        for i in [1, 4, 8, 9] {
+            aggregate.add_signature(i, &signers[i].sign(message)).unwrap();
+        }
+
+        assert_eq!(aggregate.bitmap, vec![0b0001_0010, 0b0000_0011]);
+        assert_eq!(aggregate.signers().collect::<Vec<_>>(), vec![1, 4, 8, 9]);
+        assert_eq!(aggregate.total_weight(&weights), Ok(2 + 5 + 9 + 10));
+
+        assert!(aggregate
+            .verify::<Sha256Normalized, &[u8]>(&pubkeys, &weights, 26, message)
+            .is_ok());
+        assert_eq!(
+            aggregate.verify::<Sha256Normalized, &[u8]>(&pubkeys, &weights, 27, message),
+            Err(BLSError::InsufficientWeightError)
+        );
+        assert_eq!(
+            aggregate.verify::<Sha256Normalized, &[u8]>(&pubkeys, &weights, 26, b"other"),
+            Err(BLSError::BLSVerificationError)
+        );
+
+        // Claiming a signer that did not sign invalidates the aggregate
+        let mut forged = aggregate.clone();
+        forged.bitmap[0] |= 1;
+        assert_eq!(
+            forged.verify::<Sha256Normalized, &[u8]>(&pubkeys, &weights, 26, message),
+            Err(BLSError::BLSVerificationError)
+        );
+    }
+
+    #[test]
+    fn invalid_bitmaps() {
+        let privkey = PrivKey::from_random();
+        let pubkeys = vec![G2Point::try_from(&privkey).expect("Invalid private key"); 3];
+        let signature = privkey.sign::<Sha256Normalized, &[u8]>(b"sample").unwrap();
+
+        let mut aggregate = WeightedAggregate::new(3);
+        assert_eq!(
+            aggregate.total_weight(&[1, 1, 1]),
+            Err(BLSError::SignerBitmapError)
+        );
+
+        aggregate.add_signature(0, &signature).unwrap();
+        assert_eq!(
+            aggregate.add_signature(0, &signature),
+            Err(BLSError::SignerBitmapError)
+        );
+        assert_eq!(
+            aggregate.add_signature(8, &signature),
+            Err(BLSError::SignerBitmapError)
+        );
+
+        // Indices past the key set are rejected even when they fit in the bitmap's last byte
+        assert_eq!(
+            aggregate.add_signature(5, &signature),
+            Err(BLSError::SignerBitmapError)
+        );
+        assert!(!aggregate.is_signer(5));
+
+        // Bits past the end of the key set
+        let mut padded = aggregate.clone();
+        padded.bitmap[0] |= 1 << 5;
+        assert_eq!(
+            padded.aggregate_pubkey(&pubkeys).err(),
+            Some(BLSError::SignerBitmapError)
+        );
+
+        assert_eq!(
+            aggregate.verify::<Sha256Normalized, &[u8]>(&pubkeys, &[1, 1], 1, b"sample"),
+            Err(BLSError::InputLengthError)
+        );
+
+        aggregate.add_signature(1, &signature).unwrap();
+        assert_eq!(
+            aggregate.total_weight(&[u64::MAX, 1, 0]),
+            Err(BLSError::WeightOverflowError)
+        );
+    }
+}
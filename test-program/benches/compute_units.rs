@@ -0,0 +1,50 @@
+//! Compares the compute units used to aggregate G2 points on-chain. Build the program with
+//! `cargo build-sbf --features bench` first, then run `cargo bench --features bench`.
+
+use mollusk_svm::Mollusk;
+use solana_alt_bn128_bls::{G2Point, PrivKey};
+use solana_alt_bn128_bls_test::bench::{AGGREGATE, AGGREGATE_ARKWORKS, AGGREGATE_CHECKED_ADD};
+use solana_sdk::{instruction::Instruction, pubkey, pubkey::Pubkey};
+
+const PROGRAM_ID: Pubkey = pubkey!("B1sA1tBn128111111111111111111111111111111111");
+
+fn main() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, "target/deploy/solana_alt_bn128_bls_test");
+    mollusk.compute_budget.compute_unit_limit = 1_400_000;
+
+    println!("| points | arkworks | checked_add | aggregate |");
+    println!("|-------:|---------:|------------:|----------:|");
+
+    for n in [2, 4, 8, 16] {
+        let points: Vec<G2Point> = (0..n)
+            .map(|_| G2Point::try_from(&PrivKey::from_random()).expect("Invalid private key"))
+            .collect();
+        let expected = G2Point::aggregate(&points).unwrap();
+
+        let data: Vec<u8> = points
+            .iter()
+            .chain([&expected])
+            .flat_map(|point| point.0)
+            .collect();
+
+        let units: Vec<u64> = [AGGREGATE_ARKWORKS, AGGREGATE_CHECKED_ADD, AGGREGATE]
+            .iter()
+            .map(|discriminator| {
+                let instruction = Instruction::new_with_bytes(
+                    PROGRAM_ID,
+                    &[&[*discriminator][..], &data].concat(),
+                    vec![],
+                );
+                let result = mollusk.process_instruction(&instruction, &[]);
+                assert!(!result.program_result.is_err(), "aggregation failed");
+                result.compute_units_consumed
+            })
+            .collect();
+
+        println!("| {n:>6} | {:>8} | {:>11} | {:>9} |", units[0], units[1], units[2]);
+
+        // A single inversion must beat one inversion per addition, and arkworks' decompression
+        assert!(units[2] < units[1], "aggregate used more units than checked_add");
+        assert!(units[2] < units[0], "aggregate used more units than arkworks");
+    }
+}
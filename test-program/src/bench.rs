@@ -0,0 +1,61 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use num::CheckedAdd;
+use pinocchio::{program_error::ProgramError, ProgramResult};
+use solana_alt_bn128_bls::{G2CompressedPoint, G2Point};
+
+/// Sums points with the original arkworks based addition, which compresses both points and
+/// round-trips them through `ark_bn254::G2Affine`.
+pub const AGGREGATE_ARKWORKS: u8 = 0xf0;
+/// Sums points with repeated `G2Point::checked_add`, paying one inversion per addition.
+pub const AGGREGATE_CHECKED_ADD: u8 = 0xf1;
+/// Sums points with `G2Point::aggregate`, paying a single inversion.
+pub const AGGREGATE: u8 = 0xf2;
+
+/// Benchmarks G2 aggregation. Instruction data is any number of 128 byte `G2Point`s followed by
+/// their expected sum, so the work can't be skipped and the result is checked on-chain.
+pub fn process(discriminator: u8, data: &[u8]) -> ProgramResult {
+    if data.len() < 256 || !data.len().is_multiple_of(128) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (points, expected) = data.split_at(data.len() - 128);
+    let points: Vec<G2Point> = points
+        .chunks_exact(128)
+        .map(|chunk| G2Point(chunk.try_into().unwrap()))
+        .collect();
+
+    let sum = match discriminator {
+        AGGREGATE_ARKWORKS => points[1..]
+            .iter()
+            .try_fold(points[0], |acc, point| arkworks_add(&acc, point)),
+        AGGREGATE_CHECKED_ADD => points[1..]
+            .iter()
+            .try_fold(points[0], |acc, point| acc.checked_add(point)),
+        AGGREGATE => G2Point::aggregate(&points).ok(),
+        _ => None,
+    }
+    .ok_or(ProgramError::InvalidInstructionData)?;
+
+    if sum.0 != expected {
+        return Err(ProgramError::InvalidArgument);
+    }
+    Ok(())
+}
+
+fn arkworks_add(lhs: &G2Point, rhs: &G2Point) -> Option<G2Point> {
+    let mut s0 = G2CompressedPoint::try_from(lhs).ok()?.0;
+    let mut s1 = G2CompressedPoint::try_from(rhs).ok()?.0;
+
+    s0.reverse();
+    s1.reverse();
+
+    let g2_agg = ark_bn254::G2Affine::deserialize_compressed(&s0[..]).ok()?
+        + ark_bn254::G2Affine::deserialize_compressed(&s1[..]).ok()?;
+
+    let mut g2_agg_bytes = [0u8; 64];
+    g2_agg.serialize_compressed(&mut &mut g2_agg_bytes[..]).ok()?;
+
+    g2_agg_bytes.reverse();
+
+    G2Point::try_from(G2CompressedPoint(g2_agg_bytes)).ok()
+}
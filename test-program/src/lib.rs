@@ -1,67 +1,331 @@
-use solana_alt_bn128_bls::{G1CompressedPoint, G2CompressedPoint, Sha256Normalized};
 use pinocchio::{account_info::AccountInfo, entrypoint, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use solana_alt_bn128_bls::{G1CompressedPoint, G2CompressedPoint, Sha256Normalized};
+
+pub mod state;
+use state::Registry;
 
-pub const PUBKEY: G2CompressedPoint = G2CompressedPoint([
-    0x26, 0xe7, 0x58, 0x78, 0x4a, 0x55, 0x3f, 0xe9, 0xe9, 0x09, 0x2c, 0xdd, 0x05, 0xea, 0xa3, 0xb9,
-    0x2c, 0xb5, 0xd9, 0x30, 0xa1, 0xd4, 0x8b, 0xde, 0xb9, 0xbe, 0x8f, 0x0f, 0x6f, 0x09, 0xc9, 0xda,
-    0x08, 0x20, 0xd2, 0x42, 0xbc, 0x90, 0x71, 0xa8, 0x49, 0x8b, 0x46, 0x87, 0xa2, 0x51, 0x9f, 0xb0,
-    0x22, 0xb2, 0xec, 0xb4, 0xcb, 0x99, 0x34, 0xdb, 0x57, 0xc1, 0xc0, 0x03, 0xda, 0x3c, 0x1f, 0x83,
-]);
+#[cfg(feature = "bench")]
+pub mod bench;
 
 #[no_mangle]
 pub static IDL: &str = "https://github.com/org/repo/idl.json";
 
+/// # Instructions
+///
+/// - `0` Initialize: `[registry (writable), authority (signer)]`
+/// - `1` AddMember: `pubkey (64) || proof of possession (32)`, `[registry (writable), authority (signer)]`
+/// - `2` RemoveMember: `pubkey (64)`, `[registry (writable), authority (signer)]`
+/// - `3` Verify: `signature (32) || message`, `[registry]`
+///
+/// Public keys are `G2CompressedPoint`s, signatures and proofs of possession are
+/// `G1CompressedPoint`s, and the registry account must be owned by this program and
+/// `Registry::LEN` bytes long.
+pub mod instruction {
+    pub const INITIALIZE: u8 = 0;
+    pub const ADD_MEMBER: u8 = 1;
+    pub const REMOVE_MEMBER: u8 = 2;
+    pub const VERIFY: u8 = 3;
+}
+
 entrypoint!(process_instruction);
 
 fn process_instruction(
-    _program_id: &Pubkey,
-    _accounts: &[AccountInfo],
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
     instruction_data: &[u8],  // Serialized instruction-specific data
 ) -> ProgramResult {
-    let (signature_bytes, message) = instruction_data.split_at(32);
+    let (discriminator, data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
 
-    let signature = G1CompressedPoint(signature_bytes.try_into().unwrap());
+    match *discriminator {
+        instruction::INITIALIZE => initialize(program_id, accounts),
+        instruction::ADD_MEMBER => add_member(program_id, accounts, data),
+        instruction::REMOVE_MEMBER => remove_member(program_id, accounts, data),
+        instruction::VERIFY => verify(program_id, accounts, data),
+        #[cfg(feature = "bench")]
+        bench::AGGREGATE_ARKWORKS | bench::AGGREGATE_CHECKED_ADD | bench::AGGREGATE => {
+            bench::process(*discriminator, data)
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Checks the registry is owned by this program and writable.
+fn check_registry(program_id: &Pubkey, registry: &AccountInfo) -> ProgramResult {
+    if registry.owner() != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !registry.is_writable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Checks `authority` signed and matches the registry's authority.
+fn check_authority(registry: &Registry, authority: &AccountInfo) -> ProgramResult {
+    if !authority.is_signer() || registry.authority != *authority.key() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+fn initialize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [registry_account, authority] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_registry(program_id, registry_account)?;
+
+    if !authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = registry_account.try_borrow_mut_data()?;
+    let registry = Registry::load_mut(&mut data)?;
 
-    PUBKEY
-        .verify_signature::<Sha256Normalized, &[u8], G1CompressedPoint>(signature, &message).map_err(|_| ProgramError::MissingRequiredSignature)?;
+    if registry.authority != Pubkey::default() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    registry.authority = *authority.key();
     Ok(())
 }
 
+fn add_member(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [registry_account, authority] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_registry(program_id, registry_account)?;
+
+    if data.len() != 96 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (pubkey, proof) = data.split_at(64);
+    let pubkey = G2CompressedPoint(pubkey.try_into().unwrap());
+    let proof = G1CompressedPoint(proof.try_into().unwrap());
+
+    let mut account_data = registry_account.try_borrow_mut_data()?;
+    let registry = Registry::load_mut(&mut account_data)?;
+    check_authority(registry, authority)?;
+
+    // Reject the identity and points outside the prime order subgroup
+    pubkey.validate().map_err(|_| ProgramError::InvalidArgument)?;
+
+    // Only keys with a valid proof of possession may join, preventing rogue key attacks
+    pubkey
+        .verify_possession::<Sha256Normalized, G1CompressedPoint>(proof)
+        .map_err(|_| ProgramError::InvalidArgument)?;
+
+    registry.add(&pubkey.0)
+}
+
+fn remove_member(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [registry_account, authority] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    check_registry(program_id, registry_account)?;
+
+    let pubkey: [u8; 64] = data
+        .try_into()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let mut account_data = registry_account.try_borrow_mut_data()?;
+    let registry = Registry::load_mut(&mut account_data)?;
+    check_authority(registry, authority)?;
+
+    registry.remove(&pubkey)
+}
+
+fn verify(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [registry_account, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if registry_account.owner() != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if data.len() < 32 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (signature_bytes, message) = data.split_at(32);
+    let signature = G1CompressedPoint(signature_bytes.try_into().unwrap());
+
+    let account_data = registry_account.try_borrow_data()?;
+    let registry = Registry::load(&account_data)?;
+
+    if registry.count() == 0 {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    G2CompressedPoint(registry.aggregate)
+        .verify_signature::<Sha256Normalized, &[u8], G1CompressedPoint>(signature, message)
+        .map_err(|_| ProgramError::MissingRequiredSignature)
+}
+
 #[cfg(test)]
 mod tests {
-    use mollusk_svm::Mollusk;
+    use mollusk_svm::{result::Check, Mollusk};
+    use solana_alt_bn128_bls::{G1CompressedPoint, G1Point, G2CompressedPoint, PrivKey, Sha256Normalized};
     use solana_sdk::pubkey;
     use solana_sdk::pubkey::Pubkey;
     use solana_sdk::{
-        account::AccountSharedData,
+        account::{AccountSharedData, ReadableAccount},
         instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
     };
 
+    use crate::{instruction, state::Registry};
+
+    const PROGRAM_ID: Pubkey = pubkey!("B1sA1tBn128111111111111111111111111111111111");
+    const PROGRAM_PATH: &str = "target/deploy/solana_alt_bn128_bls_test";
+
+    struct Member {
+        privkey: PrivKey,
+        pubkey: G2CompressedPoint,
+    }
+
+    impl Member {
+        fn new() -> Self {
+            let privkey = PrivKey::from_random();
+            let pubkey = G2CompressedPoint::try_from(&privkey).expect("Invalid private key");
+            Self { privkey, pubkey }
+        }
+
+        fn proof(&self) -> G1CompressedPoint {
+            let proof = self.privkey.prove_possession::<Sha256Normalized>().unwrap();
+            G1CompressedPoint::try_from(proof).unwrap()
+        }
+
+        fn sign(&self, message: &[u8]) -> G1CompressedPoint {
+            let signature = self.privkey.sign::<Sha256Normalized, &[u8]>(message).unwrap();
+            G1CompressedPoint::try_from(signature).unwrap()
+        }
+    }
+
+    fn ix(data: Vec<u8>, registry: Pubkey, authority: Pubkey) -> Instruction {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![AccountMeta::new(registry, false), AccountMeta::new_readonly(authority, true)],
+        )
+    }
+
+    fn add_member_ix(pubkey: &G2CompressedPoint, proof: &G1CompressedPoint, registry: Pubkey, authority: Pubkey) -> Instruction {
+        ix([&[instruction::ADD_MEMBER][..], &pubkey.0, &proof.0].concat(), registry, authority)
+    }
+
+    fn remove_member_ix(member: &Member, registry: Pubkey, authority: Pubkey) -> Instruction {
+        ix([&[instruction::REMOVE_MEMBER][..], &member.pubkey.0].concat(), registry, authority)
+    }
+
+    fn verify_ix(signature: &G1CompressedPoint, message: &[u8], registry: Pubkey) -> Instruction {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[&[instruction::VERIFY][..], &signature.0, message].concat(),
+            vec![AccountMeta::new_readonly(registry, false)],
+        )
+    }
+
+    /// Aggregates compressed signatures off-chain.
+    fn aggregate_signatures(signatures: &[G1CompressedPoint]) -> G1CompressedPoint {
+        let sum = signatures
+            .iter()
+            .map(|s| G1Point::try_from(s).unwrap())
+            .reduce(|acc, s| acc + s)
+            .unwrap();
+        G1CompressedPoint::try_from(sum).unwrap()
+    }
+
     #[test]
     fn test() {
-        let program_id = pubkey!("B1sA1tBn128111111111111111111111111111111111");
+        assert!(
+            std::path::Path::new(PROGRAM_PATH).with_extension("so").exists(),
+            "{PROGRAM_PATH}.so not found, run `cargo build-sbf` in test-program first"
+        );
+        let mollusk = Mollusk::new(&PROGRAM_ID, PROGRAM_PATH);
+
+        let registry = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
 
-        let signer = Pubkey::new_unique();
+        let mut accounts = vec![
+            (registry, AccountSharedData::new(100_000_000, Registry::LEN, &PROGRAM_ID)),
+            (authority, AccountSharedData::new(10_000, 0, &Pubkey::default())),
+        ];
 
-        let instruction_data: Vec<u8> = [
-            &[
-                0x2b, 0x04, 0x16, 0xb5, 0xd0, 0x58, 0xe8, 0xb3, 0x13, 0x42, 0x4d, 0x3e, 0x71, 0xec, 0x61,
-                0xa3, 0x62, 0x42, 0xdb, 0xa0, 0x31, 0xc3, 0x53, 0xd9, 0xa0, 0x21, 0xbe, 0x4f, 0x5a, 0xed,
-                0x22, 0x7a,
-            ],
-            &50_000u64.to_le_bytes()[..], 
-            b"BTCUSD<"
-        ].concat();
+        let mut process = |instruction: &Instruction, check: Check| {
+            let result = mollusk.process_and_validate_instruction(instruction, &accounts, &[check]);
+            accounts = result.resulting_accounts;
+        };
 
+        process(&ix(vec![instruction::INITIALIZE], registry, authority), Check::success());
+        process(
+            &ix(vec![instruction::INITIALIZE], registry, authority),
+            Check::err(ProgramError::AccountAlreadyInitialized),
+        );
+
+        let members: Vec<Member> = (0..3).map(|_| Member::new()).collect();
+        for member in members.iter() {
+            process(&add_member_ix(&member.pubkey, &member.proof(), registry, authority), Check::success());
+        }
+
+        // Duplicate members and members without a valid proof of possession are rejected
+        process(
+            &add_member_ix(&members[0].pubkey, &members[0].proof(), registry, authority),
+            Check::err(ProgramError::InvalidArgument),
+        );
+        let outsider = Member::new();
+        process(
+            &add_member_ix(&outsider.pubkey, &members[0].proof(), registry, authority),
+            Check::err(ProgramError::InvalidArgument),
+        );
 
-        let instruction =
-            Instruction::new_with_bytes(program_id, &instruction_data, vec![AccountMeta::new(signer, true)]);
+        // A rogue key x·G2 - pk_victim would let its owner forge the aggregate alone with x, but
+        // its owner cannot prove possession of it
+        let x = PrivKey::from_random();
+        let x_g2 = G2CompressedPoint::try_from(&x).expect("Invalid private key");
+        let rogue = x_g2 - members[0].pubkey;
+        let message = [&60_000u64.to_le_bytes()[..], b"BTCUSD<"].concat();
+        let forged = G1CompressedPoint::try_from(x.sign::<Sha256Normalized, &[u8]>(&message).unwrap()).unwrap();
+        assert!([members[0].pubkey, rogue]
+            .iter()
+            .sum::<G2CompressedPoint>()
+            .verify_signature::<Sha256Normalized, &[u8], G1CompressedPoint>(forged, &message)
+            .is_ok());
+        let x_proof = G1CompressedPoint::try_from(x.prove_possession::<Sha256Normalized>().unwrap()).unwrap();
+        for proof in [x_proof, members[0].proof()] {
+            process(&add_member_ix(&rogue, &proof, registry, authority), Check::err(ProgramError::InvalidArgument));
+        }
 
-        let mollusk = Mollusk::new(&program_id, "target/deploy/solana_bls_alt_bn128_test");
+        // Only the authority may manage members
+        process(
+            &add_member_ix(&outsider.pubkey, &outsider.proof(), registry, Pubkey::new_unique()),
+            Check::err(ProgramError::MissingRequiredSignature),
+        );
+
+        // The aggregate signature of all members verifies
+        let message = [&50_000u64.to_le_bytes()[..], b"BTCUSD<"].concat();
+        let signatures: Vec<G1CompressedPoint> = members.iter().map(|m| m.sign(&message)).collect();
+        process(&verify_ix(&aggregate_signatures(&signatures), &message, registry), Check::success());
+        process(
+            &verify_ix(&aggregate_signatures(&signatures[..2]), &message, registry),
+            Check::err(ProgramError::MissingRequiredSignature),
+        );
 
-        let _: mollusk_svm::result::InstructionResult = mollusk.process_instruction(
-            &instruction,
-            &[(signer, AccountSharedData::new(10000, 0, &Pubkey::default()))],
+        // After removing a member, the remaining members' aggregate verifies
+        process(&remove_member_ix(&members[2], registry, authority), Check::success());
+        process(&verify_ix(&aggregate_signatures(&signatures[..2]), &message, registry), Check::success());
+        process(
+            &verify_ix(&aggregate_signatures(&signatures), &message, registry),
+            Check::err(ProgramError::MissingRequiredSignature),
         );
+        process(
+            &remove_member_ix(&members[2], registry, authority),
+            Check::err(ProgramError::InvalidArgument),
+        );
+
+        let data = accounts[0].1.data().to_vec();
+        let state = Registry::load(&data).unwrap();
+        assert_eq!(state.count(), 2);
+        assert_eq!(state.authority, authority.to_bytes());
     }
 }
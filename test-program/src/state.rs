@@ -0,0 +1,109 @@
+use num::{CheckedAdd, CheckedSub};
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use solana_alt_bn128_bls::{G2CompressedPoint, G2Point};
+
+/// Maximum number of members a registry can hold.
+pub const MAX_MEMBERS: usize = 64;
+
+/// # Registry
+///
+/// A set of `G2CompressedPoint` members managed by `authority`, along with the aggregate of all
+/// member public keys. The aggregate is all zeros while the registry is empty.
+#[repr(C)]
+pub struct Registry {
+    pub authority: Pubkey,
+    count: [u8; 4],
+    pub aggregate: [u8; 64],
+    members: [[u8; 64]; MAX_MEMBERS],
+}
+
+impl Registry {
+    pub const LEN: usize = core::mem::size_of::<Registry>();
+
+    /// Interprets account data as a `Registry`. Every field is a byte array, so any data of the
+    /// right length is a valid registry.
+    pub fn load(data: &[u8]) -> Result<&Registry, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { &*(data.as_ptr() as *const Registry) })
+    }
+
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Registry, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Registry) })
+    }
+
+    pub fn count(&self) -> usize {
+        u32::from_le_bytes(self.count) as usize
+    }
+
+    pub fn members(&self) -> &[[u8; 64]] {
+        &self.members[..self.count()]
+    }
+
+    pub fn contains(&self, member: &[u8; 64]) -> bool {
+        self.members().contains(member)
+    }
+
+    /// Appends `member` and adds it to the aggregate.
+    pub fn add(&mut self, member: &[u8; 64]) -> Result<(), ProgramError> {
+        let count = self.count();
+        if count == MAX_MEMBERS {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        if self.contains(member) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.aggregate = if count == 0 {
+            *member
+        } else {
+            aggregate(&[self.aggregate, *member])?
+        };
+
+        self.members[count] = *member;
+        self.count = (count as u32 + 1).to_le_bytes();
+        Ok(())
+    }
+
+    /// Swap-removes `member` and subtracts it from the aggregate.
+    pub fn remove(&mut self, member: &[u8; 64]) -> Result<(), ProgramError> {
+        let count = self.count();
+        let index = self
+            .members()
+            .iter()
+            .position(|m| m == member)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        self.members[index] = self.members[count - 1];
+        self.members[count - 1] = [0u8; 64];
+        self.count = (count as u32 - 1).to_le_bytes();
+
+        self.aggregate = G2CompressedPoint(self.aggregate)
+            .checked_sub(&G2CompressedPoint(*member))
+            .ok_or(ProgramError::InvalidAccountData)?
+            .0;
+        Ok(())
+    }
+}
+
+/// Sums a non-empty list of compressed G2 points.
+fn aggregate(points: &[[u8; 64]]) -> Result<[u8; 64], ProgramError> {
+    let mut sum = G2Point::try_from(G2CompressedPoint(points[0]))
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    for point in &points[1..] {
+        let point = G2Point::try_from(G2CompressedPoint(*point))
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        sum = sum
+            .checked_add(&point)
+            .ok_or(ProgramError::InvalidAccountData)?;
+    }
+
+    Ok(G2CompressedPoint::try_from(&sum)
+        .map_err(|_| ProgramError::InvalidAccountData)?
+        .0)
+}